@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::math::MathError;
 
 #[error_code]
 pub enum TokenError {
@@ -24,4 +25,108 @@ pub enum TokenError {
     UnauthorizedOracleUpdate,
     #[msg("Invalid platform wallet provided")]
     InvalidPlatformWallet,
-}
\ No newline at end of file
+    #[msg("Insufficient token balance for sale")]
+    InsufficientTokenBalance,
+    #[msg("Vault does not hold enough SOL for this payout")]
+    InsufficientVaultFunds,
+    #[msg("Trade output is below the requested minimum")]
+    SlippageExceeded,
+    #[msg("Total cost exceeds the maximum the buyer accepted")]
+    MaxCostExceeded,
+    #[msg("Transaction deadline has passed")]
+    DeadlineExpired,
+    #[msg("Invalid bonding curve parameters")]
+    InvalidCurveParams,
+    #[msg("Price parameter is outside the platform bounds")]
+    PriceParamOutOfBounds,
+    #[msg("Price bounds must have min less than or equal to max")]
+    InvalidPriceBounds,
+    #[msg("Price parameters are locked once supply passes the platform threshold")]
+    PriceParamsLocked,
+    #[msg("Fee schedule exceeds the platform fee cap")]
+    FeeTooHigh,
+    #[msg("Only the song's creator can do this")]
+    UnauthorizedCreator,
+    #[msg("No fees to claim")]
+    NoFeesToClaim,
+    #[msg("Buyers cannot refer themselves")]
+    SelfReferral,
+    #[msg("Not enough price history for the requested TWAP window")]
+    TwapWindowTooLong,
+    #[msg("Trading has not started for this song")]
+    TradingNotStarted,
+    #[msg("Invalid launch schedule")]
+    InvalidLaunchSchedule,
+    #[msg("Purchase exceeds the launch window per-transaction limit")]
+    LaunchTxLimitExceeded,
+    #[msg("Purchase exceeds the launch window per-wallet cap")]
+    LaunchWalletCapExceeded,
+    #[msg("Launch allocation account required during the launch window")]
+    LaunchAllocationRequired,
+    #[msg("Trading is paused on the platform")]
+    PlatformPaused,
+    #[msg("Trading is paused for this song")]
+    SongPaused,
+    #[msg("Only the pauser can do this")]
+    UnauthorizedPauser,
+    #[msg("Invalid oracle limits")]
+    InvalidOracleLimits,
+    #[msg("Oracle updated too recently; co-signature required")]
+    OracleUpdateTooSoon,
+    #[msg("View count rise exceeds the per-update maximum; co-signature required")]
+    OracleDeltaTooLarge,
+    #[msg("View count growth exceeds the per-update ratio; co-signature required")]
+    OracleGrowthTooLarge,
+    #[msg("Co-signer does not match the configured oracle co-signer")]
+    UnauthorizedCoSigner,
+    #[msg("Invalid reporter set")]
+    InvalidReporterSet,
+    #[msg("Missing ed25519 signature instruction")]
+    MissingEd25519Instruction,
+    #[msg("Attestation signature does not match")]
+    InvalidAttestation,
+    #[msg("Attestation is too old or too far in the future")]
+    AttestationExpired,
+    #[msg("Attestation nonce already used")]
+    AttestationReplayed,
+    #[msg("Oracle is stale")]
+    OracleStale,
+    #[msg("Max oracle staleness must be positive")]
+    InvalidOracleStaleness,
+    #[msg("Mint authority must be the program mint authority")]
+    InvalidMintAuthority,
+    #[msg("Mint must have no freeze authority or a platform-controlled one")]
+    InvalidFreezeAuthority,
+    #[msg("Mint must have no supply")]
+    MintSupplyNotZero,
+    #[msg("Mint decimals do not match the platform standard")]
+    InvalidMintDecimals,
+    #[msg("Invalid genesis allocation config")]
+    InvalidGenesisConfig,
+    #[msg("No vested tokens to claim")]
+    NothingVested,
+    #[msg("Symbol too long")]
+    SymbolTooLong,
+    #[msg("Metadata field too long")]
+    MetadataFieldTooLong,
+    #[msg("Transfer fees need a Token-2022 mint")]
+    TransferFeeRequiresToken2022,
+    #[msg("No transfer fees to harvest")]
+    NoTransferFees,
+    #[msg("The mint keypair must sign")]
+    MintNotSigner,
+    #[msg("View counts come from the reporter quorum; co-signature required")]
+    ReporterQuorumRequired,
+    #[msg("Mint has an extension the platform does not support")]
+    UnsupportedMintExtension,
+    #[msg("Mint extension authority must be the program")]
+    InvalidExtensionAuthority,
+}
+
+impl From<MathError> for Error {
+    fn from(err: MathError) -> Self {
+        match err {
+            MathError::Overflow => error!(TokenError::SupplyOverflow),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::state::{CurveKind, FeeSchedule, GenesisConfig, OracleLimits, PriceBounds, StalePricing};
+
+// Emitted through emit_cpi! so indexers read them from inner instructions,
+// where log truncation cannot drop them
+
+#[event]
+pub struct TradeEvent {
+    pub mint: Pubkey,
+    pub trader: Pubkey,
+    pub is_buy: bool,
+    pub amount_tokens: u64,
+    pub curve_amount: u64,           // Cost paid or proceeds paid out, before fees
+    pub platform_fee: u64,           // Includes the referral fee
+    pub creator_fee: u64,
+    pub referral_fee: u64,
+    pub referrer: Option<Pubkey>,
+    pub view_count: u64,
+    pub supply_before: u64,
+    pub supply_after: u64,
+    pub price_before: u64,
+    pub price_after: u64,
+    pub total_collected_before: u64,
+    pub total_collected_after: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub previous_view_count: u64,
+    pub new_view_count: u64,
+    pub previous_price: u64,
+    pub new_price: u64,
+    pub previous_updated_at: i64,
+    pub co_signed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReporterSetUpdated {
+    pub authority: Pubkey,
+    pub previous_reporters: Vec<Pubkey>,
+    pub new_reporters: Vec<Pubkey>,
+    pub previous_quorum: u8,
+    pub new_quorum: u8,
+    pub max_report_age_secs: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenCreated {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub id: u64,
+    pub name: String,
+    pub music_uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PlatformUpdated {
+    pub authority: Pubkey,
+    pub previous_platform_wallet: Pubkey,
+    pub new_platform_wallet: Pubkey,
+    pub previous_oracle_authority: Pubkey,
+    pub new_oracle_authority: Pubkey,
+    pub previous_price_bounds: PriceBounds,
+    pub new_price_bounds: PriceBounds,
+    pub previous_price_lock_supply: u64,
+    pub new_price_lock_supply: u64,
+    pub previous_fee_schedule: FeeSchedule,
+    pub new_fee_schedule: FeeSchedule,
+    pub previous_pauser: Pubkey,
+    pub new_pauser: Pubkey,
+    pub previous_oracle_limits: OracleLimits,
+    pub new_oracle_limits: OracleLimits,
+    pub previous_max_oracle_staleness_secs: i64,
+    pub new_max_oracle_staleness_secs: i64,
+    pub previous_genesis: GenesisConfig,
+    pub new_genesis: GenesisConfig,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultThresholdReached {
+    pub mint: Pubkey,
+    pub raydium_pool: Option<Pubkey>,
+    pub liquidity_threshold: u64,
+    pub total_collected: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub mint: Option<Pubkey>,        // None when the whole platform was toggled
+    pub pauser: Pubkey,
+    pub previous_paused: bool,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PriceParamsUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub previous_curve: CurveKind,
+    pub new_curve: CurveKind,
+    pub supply: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StalePricingUpdated {
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub previous_stale_pricing: StalePricing,
+    pub stale_pricing: StalePricing,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferFeesHarvested {
+    pub mint: Pubkey,
+    pub harvested: u64,
+    pub creator_amount: u64,
+    pub platform_amount: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::CreatorFees;
+use crate::error::TokenError;
+use crate::utils::withdraw_surplus_lamports;
+
+#[derive(Accounts)]
+pub struct ClaimCreatorFees<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_fees", mint.key().as_ref()],
+        bump = creator_fees.bump,
+        has_one = creator @ TokenError::UnauthorizedCreator
+    )]
+    pub creator_fees: Account<'info, CreatorFees>,
+}
+
+pub fn handler(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+    let claimable = withdraw_surplus_lamports(
+        &ctx.accounts.creator_fees.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        CreatorFees::SPACE,
+    )?;
+
+    let creator_fees = &mut ctx.accounts.creator_fees;
+    creator_fees.total_claimed = creator_fees.total_claimed
+        .checked_add(claimable)
+        .ok_or(TokenError::SupplyOverflow)?;
+
+    msg!("Claimed creator fees: {} lamports", claimable);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::Referral;
+use crate::error::TokenError;
+use crate::utils::withdraw_surplus_lamports;
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump = referral.bump,
+        has_one = referrer
+    )]
+    pub referral: Account<'info, Referral>,
+}
+
+pub fn handler(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let claimable = withdraw_surplus_lamports(
+        &ctx.accounts.referral.to_account_info(),
+        &ctx.accounts.referrer.to_account_info(),
+        Referral::SPACE,
+    )?;
+
+    let referral = &mut ctx.accounts.referral;
+    referral.total_claimed = referral.total_claimed
+        .checked_add(claimable)
+        .ok_or(TokenError::SupplyOverflow)?;
+
+    msg!("Claimed referral fees: {} lamports", claimable);
+    Ok(())
+}
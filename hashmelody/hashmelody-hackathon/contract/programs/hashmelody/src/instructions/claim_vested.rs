@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenInterface, TokenAccount, transfer_checked, TransferChecked},
+    associated_token::AssociatedToken,
+};
+use crate::state::GenesisAllocation;
+use crate::error::TokenError;
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"genesis_allocation", mint.key().as_ref()],
+        bump = genesis_allocation.bump,
+        has_one = creator @ TokenError::UnauthorizedCreator
+    )]
+    pub genesis_allocation: Account<'info, GenesisAllocation>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = genesis_allocation,
+        associated_token::token_program = token_program
+    )]
+    pub vesting_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let allocation = &ctx.accounts.genesis_allocation;
+    let claimable = allocation.claimable(now);
+    require!(claimable > 0, TokenError::NothingVested);
+
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"genesis_allocation".as_ref(),
+        mint_key.as_ref(),
+        &[allocation.bump],
+    ];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vesting_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.genesis_allocation.to_account_info(),
+            },
+            &[&seeds[..]],
+        ),
+        claimable,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let allocation = &mut ctx.accounts.genesis_allocation;
+    allocation.claimed = allocation.claimed
+        .checked_add(claimable)
+        .ok_or(TokenError::SupplyOverflow)?;
+
+    msg!("Claimed vested tokens: {}", claimable);
+    Ok(())
+}
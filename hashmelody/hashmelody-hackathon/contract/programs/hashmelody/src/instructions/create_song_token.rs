@@ -0,0 +1,468 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{
+        TokenInterface, initialize_mint2, InitializeMint2, find_mint_account_size,
+        metadata_pointer_initialize, MetadataPointerInitialize, token_metadata_initialize,
+        TokenMetadataInitialize, token_metadata_update_field, TokenMetadataUpdateField,
+        transfer_fee_initialize, TransferFeeInitialize,
+        spl_token_2022::{self, extension::ExtensionType},
+        spl_token_metadata_interface::state::{Field, TokenMetadata as NativeMetadata},
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+    },
+    associated_token::{AssociatedToken, get_associated_token_address_with_program_id, create, Create},
+};
+use crate::state::{
+    MintAuthority, TokenMetadata, CreatorFees, ViewershipOracle, TokenVault, PriceHistory,
+    PlatformConfig, CurveKind, LaunchSchedule, GenesisAllocation, RoyaltyConfig, MAX_NAME_LEN,
+    MAX_URI_LEN, MAX_SYMBOL_LEN, MAX_FIELD_LEN, TOKEN_DECIMALS,
+};
+use crate::instructions::{mint_genesis_allocation, fund_sol_vault};
+use crate::events::TokenCreated;
+use crate::error::TokenError;
+
+// Launches a song in one transaction: the mint, its metadata, oracle,
+// vault, price history and token accounts, then the genesis allocation
+// with the creator's share in vesting escrow.
+// Replaces the five setup steps, which stay available.
+//
+// Works with either token program. Under Token-2022 the mint also carries
+// its own metadata (name, symbol, uri, artist and genre) through the
+// MetadataPointer and TokenMetadata extensions, so wallets can read it,
+// and may charge a transfer fee that pays royalties on secondary trades.
+//
+// The mint is a PDA whose authority PDA is seeded by the mint itself, so
+// it and the token accounts on it are created in the handler rather than
+// through `init`.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(id: u64)]
+pub struct CreateSongToken<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Box<Account<'info, PlatformConfig>>,
+
+    #[account(
+        address = platform_config.platform_wallet
+    )]
+    pub platform_wallet: SystemAccount<'info>,
+
+    /// CHECK: Created as a mint in the handler
+    #[account(
+        mut,
+        seeds = [b"song_mint", creator.key().as_ref(), &id.to_le_bytes()],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + 1,
+        seeds = [b"mint_authority", mint.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = TokenMetadata::SPACE,
+        seeds = [b"metadata", mint.key().as_ref(), &id.to_le_bytes()],
+        bump
+    )]
+    pub metadata: Box<Account<'info, TokenMetadata>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = CreatorFees::SPACE,
+        seeds = [b"creator_fees", mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fees: Box<Account<'info, CreatorFees>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ViewershipOracle::INIT_SPACE,
+        seeds = [b"viewership_oracle", mint.key().as_ref()],
+        bump
+    )]
+    pub oracle: Box<Account<'info, ViewershipOracle>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = TokenVault::SPACE,
+        seeds = [b"token_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Box<Account<'info, TokenVault>>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_vault", token_vault.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = PriceHistory::SPACE,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = GenesisAllocation::SPACE,
+        seeds = [b"genesis_allocation", mint.key().as_ref()],
+        bump
+    )]
+    pub genesis_allocation: Box<Account<'info, GenesisAllocation>>,
+
+    /// CHECK: Created as the vesting escrow's associated token account in the handler
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &genesis_allocation.key(),
+            &mint.key(),
+            &token_program.key()
+        )
+    )]
+    pub vesting_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Created as the platform wallet's associated token account in the handler
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &platform_wallet.key(),
+            &mint.key(),
+            &token_program.key()
+        )
+    )]
+    pub platform_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Created as the token vault's associated token account in the handler
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &token_vault.key(),
+            &mint.key(),
+            &token_program.key()
+        )
+    )]
+    pub vault_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handler(
+    ctx: Context<CreateSongToken>,
+    id: u64,
+    name: String,
+    symbol: String,
+    music_uri: String,
+    artist: String,
+    genre: String,
+    curve: Option<CurveKind>,
+    launch: Option<LaunchSchedule>,
+    royalty: Option<RoyaltyConfig>,
+) -> Result<()> {
+    // Validate lengths
+    require!(name.len() <= MAX_NAME_LEN, TokenError::NameTooLong);
+    require!(music_uri.len() <= MAX_URI_LEN, TokenError::UriTooLong);
+    require!(symbol.len() <= MAX_SYMBOL_LEN, TokenError::SymbolTooLong);
+    require!(
+        artist.len() <= MAX_FIELD_LEN && genre.len() <= MAX_FIELD_LEN,
+        TokenError::MetadataFieldTooLong
+    );
+
+    // Songs without a chosen curve keep the original quadratic defaults
+    let curve = curve.unwrap_or_default();
+    curve.validate()?;
+    curve.check_bounds(&ctx.accounts.platform_config.price_bounds)?;
+
+    if let Some(royalty) = royalty {
+        royalty.validate()?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let launch = launch.unwrap_or(LaunchSchedule::immediate(now));
+    launch.validate()?;
+
+    let creator = ctx.accounts.creator.key();
+    let mint_key = ctx.accounts.mint.key();
+
+    // The mint authority signs for the native metadata
+    ctx.accounts.mint_authority.bump = ctx.bumps.mint_authority;
+
+    let native_metadata = NativeMetadata {
+        update_authority: OptionalNonZeroPubkey(ctx.accounts.mint_authority.key()),
+        mint: mint_key,
+        name: name.clone(),
+        symbol,
+        uri: music_uri.clone(),
+        additional_metadata: vec![
+            ("artist".to_string(), artist),
+            ("genre".to_string(), genre),
+        ],
+    };
+    ctx.accounts.create_mint(id, ctx.bumps.mint, &native_metadata, royalty)?;
+    ctx.accounts.create_token_accounts()?;
+    fund_sol_vault(
+        ctx.accounts.creator.to_account_info(),
+        ctx.accounts.sol_vault.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent,
+    )?;
+
+    // Record the per-song state
+
+    let metadata = &mut ctx.accounts.metadata;
+    metadata.mint = mint_key;
+    metadata.creator = creator;
+    metadata.id = id;
+    metadata.name = name.clone();
+    metadata.music_uri = music_uri.clone();
+
+    let creator_fees = &mut ctx.accounts.creator_fees;
+    creator_fees.mint = mint_key;
+    creator_fees.creator = creator;
+    creator_fees.bump = ctx.bumps.creator_fees;
+
+    let vault_token_account = ctx.accounts.vault_token_account.key();
+    let sol_vault = ctx.accounts.sol_vault.key();
+    let vault = &mut ctx.accounts.token_vault;
+    vault.initialize(mint_key, launch, ctx.bumps.token_vault);
+    vault.vault_account = vault_token_account;
+    vault.sol_vault = sol_vault;
+    vault.sol_vault_bump = ctx.bumps.sol_vault;
+
+    let mut price_history = ctx.accounts.price_history.load_init()?;
+    price_history.mint = mint_key;
+    price_history.bump = ctx.bumps.price_history;
+    drop(price_history);
+
+    ctx.accounts.genesis_allocation.initialize(
+        mint_key,
+        creator,
+        &ctx.accounts.platform_config.genesis,
+        now,
+        ctx.bumps.genesis_allocation,
+    );
+    let accounts = &ctx.accounts;
+    mint_genesis_allocation(
+        &accounts.genesis_allocation,
+        accounts.token_program.to_account_info(),
+        accounts.mint.to_account_info(),
+        accounts.mint_authority.to_account_info(),
+        accounts.mint_authority.bump,
+        accounts.vesting_token_account.to_account_info(),
+        accounts.platform_token_account.to_account_info(),
+    )?;
+
+    // Opened after genesis so the price reflects the supply trades see. The
+    // genesis allocation is not bought through the curve, so that supply
+    // is the vault's curve supply, not the mint's
+    let supply = ctx.accounts.token_vault.curve_supply;
+    ctx.accounts.oracle.initialize(mint_key, curve, supply, now, ctx.bumps.oracle)?;
+
+    emit_cpi!(TokenCreated {
+        mint: mint_key,
+        creator,
+        id,
+        name,
+        music_uri,
+        timestamp: now,
+    });
+
+    msg!("Song token created successfully!");
+    Ok(())
+}
+
+impl<'info> CreateSongToken<'info> {
+    fn create_mint(
+        &self,
+        id: u64,
+        bump: u8,
+        native_metadata: &NativeMetadata,
+        royalty: Option<RoyaltyConfig>,
+    ) -> Result<()> {
+        let creator_key = self.creator.key();
+        let id_bytes = id.to_le_bytes();
+        let mint_seeds = &[
+            b"song_mint".as_ref(),
+            creator_key.as_ref(),
+            id_bytes.as_ref(),
+            &[bump],
+        ];
+
+        // The legacy program has no room for metadata on the mint
+        let with_metadata = self.token_program.key() == spl_token_2022::ID;
+        require!(
+            with_metadata || royalty.is_none(),
+            TokenError::TransferFeeRequiresToken2022
+        );
+        let extensions = with_metadata.then(|| {
+            let mut extensions = vec![ExtensionType::MetadataPointer];
+            if royalty.is_some() {
+                extensions.push(ExtensionType::TransferFeeConfig);
+            }
+            extensions
+        });
+        let space = find_mint_account_size(extensions.as_ref())?;
+
+        // Token-2022 grows the mint as metadata is written, so it is funded
+        // for its final size up front
+        let metadata_space = if with_metadata {
+            native_metadata.tlv_size_of()?
+        } else {
+            0
+        };
+
+        anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: self.creator.to_account_info(),
+                    to: self.mint.to_account_info(),
+                },
+                &[&mint_seeds[..]],
+            ),
+            self.rent.minimum_balance(space + metadata_space),
+            space as u64,
+            &self.token_program.key(),
+        )?;
+
+        // Extensions have to be in place before the mint is initialized
+        if with_metadata {
+            metadata_pointer_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    MetadataPointerInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.mint.to_account_info(),
+                    },
+                ),
+                Some(self.mint_authority.key()),
+                Some(self.mint.key()),
+            )?;
+        }
+
+        // The fee is fixed for the life of the song, and only the program
+        // can withdraw what it withholds
+        if let Some(royalty) = royalty {
+            transfer_fee_initialize(
+                CpiContext::new(
+                    self.token_program.to_account_info(),
+                    TransferFeeInitialize {
+                        token_program_id: self.token_program.to_account_info(),
+                        mint: self.mint.to_account_info(),
+                    },
+                ),
+                None,
+                Some(&self.mint_authority.key()),
+                royalty.basis_points,
+                royalty.maximum_fee,
+            )?;
+        }
+
+        // Only the program can mint, and nobody can freeze
+        initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                InitializeMint2 {
+                    mint: self.mint.to_account_info(),
+                },
+            ),
+            TOKEN_DECIMALS,
+            &self.mint_authority.key(),
+            None,
+        )?;
+
+        if with_metadata {
+            self.write_native_metadata(native_metadata)?;
+        }
+        Ok(())
+    }
+
+    // Metadata lives on the mint itself, and only the program can update it
+    fn write_native_metadata(&self, native_metadata: &NativeMetadata) -> Result<()> {
+        let mint_key = self.mint.key();
+        let auth_seeds = &[
+            b"mint_authority".as_ref(),
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&auth_seeds[..]];
+
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TokenMetadataInitialize {
+                    token_program_id: self.token_program.to_account_info(),
+                    metadata: self.mint.to_account_info(),
+                    update_authority: self.mint_authority.to_account_info(),
+                    mint_authority: self.mint_authority.to_account_info(),
+                    mint: self.mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            native_metadata.name.clone(),
+            native_metadata.symbol.clone(),
+            native_metadata.uri.clone(),
+        )?;
+
+        for (key, value) in &native_metadata.additional_metadata {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        token_program_id: self.token_program.to_account_info(),
+                        metadata: self.mint.to_account_info(),
+                        update_authority: self.mint_authority.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                Field::Key(key.clone()),
+                value.clone(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn create_token_accounts(&self) -> Result<()> {
+        let owners = [
+            (&self.vesting_token_account, self.genesis_allocation.to_account_info()),
+            (&self.platform_token_account, self.platform_wallet.to_account_info()),
+            (&self.vault_token_account, self.token_vault.to_account_info()),
+        ];
+        for (token_account, authority) in owners {
+            create(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                Create {
+                    payer: self.creator.to_account_info(),
+                    associated_token: token_account.to_account_info(),
+                    authority,
+                    mint: self.mint.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+            ))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{ViewershipOracle, PlatformConfig, OracleStaleness};
+
+#[derive(Accounts)]
+pub struct GetOracleStaleness<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"viewership_oracle", mint.key().as_ref()],
+        bump = oracle.bump
+    )]
+    pub oracle: Account<'info, ViewershipOracle>,
+}
+
+pub fn handler(ctx: Context<GetOracleStaleness>) -> Result<OracleStaleness> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(ctx.accounts.oracle.staleness(now, ctx.accounts.platform_config.max_oracle_staleness_secs))
+}
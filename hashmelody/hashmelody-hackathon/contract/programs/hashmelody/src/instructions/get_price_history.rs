@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{PriceHistory, CandleData};
+
+#[derive(Accounts)]
+pub struct GetPriceHistory<'info> {
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump = price_history.load()?.bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+}
+
+pub fn handler(
+    ctx: Context<GetPriceHistory>,
+    from_time: i64,
+    limit: u8,
+) -> Result<Vec<CandleData>> {
+    let price_history = ctx.accounts.price_history.load()?;
+    Ok(price_history.window(from_time, limit as usize))
+}
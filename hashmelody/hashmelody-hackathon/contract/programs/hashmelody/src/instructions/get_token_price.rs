@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token}; // Add Token here
-use crate::state::ViewershipOracle;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use crate::state::{ViewershipOracle, TokenVault};
 
 
 #[derive(Accounts)]
 pub struct GetTokenPrice<'info> {
     // No ownership or signer constraints on mint
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     // Simpler PDA validation without using oracle.bump
     #[account(
@@ -14,13 +14,19 @@ pub struct GetTokenPrice<'info> {
         bump,  // Anchor will find the correct bump automatically
     )]
     pub oracle: Account<'info, ViewershipOracle>,
+
+    #[account(
+        seeds = [b"token_vault", mint.key().as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
     
     // Include token program to ensure proper mint account validation
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 pub fn handler(ctx: Context<GetTokenPrice>) -> Result<u64> {
     let oracle = &ctx.accounts.oracle;
-    let current_supply = ctx.accounts.mint.supply;
+    let current_supply = ctx.accounts.token_vault.curve_supply;
     
     // Use the existing price calculation logic
     let price = oracle.calculate_price(current_supply)?;
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::ViewershipOracle;
+
+#[derive(Accounts)]
+pub struct GetTwap<'info> {
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"viewership_oracle", mint.key().as_ref()],
+        bump = oracle.bump
+    )]
+    pub oracle: Account<'info, ViewershipOracle>,
+}
+
+pub fn handler(ctx: Context<GetTwap>, window_secs: i64) -> Result<u64> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.oracle.twap(now, window_secs)
+}
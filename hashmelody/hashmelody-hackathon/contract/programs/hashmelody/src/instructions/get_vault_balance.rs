@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
+use anchor_spl::token_interface::Mint;
 use crate::state::TokenVault;
 
 #[derive(Accounts)]
@@ -10,19 +10,22 @@ pub struct GetVaultBalance<'info> {
     )]
     pub token_vault: Account<'info, TokenVault>,
     
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
-        address = token_vault.sol_vault_wallet
+        seeds = [b"sol_vault", token_vault.key().as_ref()],
+        bump = token_vault.sol_vault_bump
     )]
-    pub sol_vault_wallet: SystemAccount<'info>,
+    pub sol_vault: SystemAccount<'info>,
 }
 
 pub fn handler(ctx: Context<GetVaultBalance>) -> Result<u64> {
-    let sol_balance = ctx.accounts.sol_vault_wallet.lamports();
+    // The rent-exempt minimum seeded at setup is not part of the reserve
+    let rent_reserve = Rent::get()?.minimum_balance(0);
+    let sol_balance = ctx.accounts.sol_vault.lamports().saturating_sub(rent_reserve);
     
     msg!("SOL vault balance: {} lamports", sol_balance);
-    msg!("SOL vault address: {}", ctx.accounts.sol_vault_wallet.key());
+    msg!("SOL vault address: {}", ctx.accounts.sol_vault.key());
     msg!("Total collected (from vault data): {} lamports", ctx.accounts.token_vault.total_collected);
     
     Ok(sol_balance)
@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{
+        Mint, TokenInterface, TokenAccount, transfer_checked, TransferChecked,
+        harvest_withheld_tokens_to_mint, HarvestWithheldTokensToMint,
+        withdraw_withheld_tokens_from_mint, WithdrawWithheldTokensFromMint,
+    },
+    associated_token::AssociatedToken,
+};
+use crate::state::{MintAuthority, CreatorFees, PlatformConfig};
+use crate::events::TransferFeesHarvested;
+use crate::error::TokenError;
+
+// Sweeps transfer fees withheld on a song's token accounts (passed as
+// remaining accounts) and on the mint, and splits them between the creator
+// and the platform in proportion to their sell-side trading fees. Anyone
+// can crank it.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct HarvestTransferFees<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    // Withdraw-withheld authority of the mint
+    #[account(
+        seeds = [b"mint_authority", mint.key().as_ref()],
+        bump = mint_authority.bump,
+    )]
+    pub mint_authority: Account<'info, MintAuthority>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"creator_fees", mint.key().as_ref()],
+        bump = creator_fees.bump
+    )]
+    pub creator_fees: Account<'info, CreatorFees>,
+
+    // Holds withdrawn fees until they are split
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = mint_authority,
+        associated_token::token_program = token_program
+    )]
+    pub royalty_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key(),
+        constraint = creator_token_account.owner == creator_fees.creator
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = platform_token_account.mint == mint.key(),
+        constraint = platform_token_account.owner == platform_config.platform_wallet
+    )]
+    pub platform_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>) -> Result<()> {
+    let mint_key = ctx.accounts.mint.key();
+    let auth_seeds = &[
+        b"mint_authority".as_ref(),
+        mint_key.as_ref(),
+        &[ctx.accounts.mint_authority.bump],
+    ];
+    let signer_seeds = &[&auth_seeds[..]];
+
+    // Move fees withheld on holders' accounts onto the mint
+    if !ctx.remaining_accounts.is_empty() {
+        harvest_withheld_tokens_to_mint(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            ctx.remaining_accounts.to_vec(),
+        )?;
+    }
+
+    withdraw_withheld_tokens_from_mint(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        WithdrawWithheldTokensFromMint {
+            token_program_id: ctx.accounts.token_program.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            destination: ctx.accounts.royalty_escrow.to_account_info(),
+            authority: ctx.accounts.mint_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    ctx.accounts.royalty_escrow.reload()?;
+    let harvested = ctx.accounts.royalty_escrow.amount;
+    require!(harvested > 0, TokenError::NoTransferFees);
+
+    let creator_amount = ctx.accounts.platform_config.fee_schedule.sell.creator_share(harvested);
+    let platform_amount = harvested - creator_amount;
+
+    // Paying out is itself a transfer, so part of each share is withheld
+    // again and goes out with the next harvest
+    let payouts = [
+        (ctx.accounts.creator_token_account.to_account_info(), creator_amount),
+        (ctx.accounts.platform_token_account.to_account_info(), platform_amount),
+    ];
+    for (to, amount) in payouts {
+        if amount == 0 {
+            continue;
+        }
+        transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.royalty_escrow.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to,
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+    }
+
+    emit_cpi!(TransferFeesHarvested {
+        mint: mint_key,
+        harvested,
+        creator_amount,
+        platform_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Harvested transfer fees: {}", harvested);
+    Ok(())
+}
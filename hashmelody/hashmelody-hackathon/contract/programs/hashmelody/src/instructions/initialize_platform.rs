@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::PlatformConfig;
+use crate::state::{PlatformConfig, PriceBounds, FeeSchedule, OracleLimits, GenesisConfig};
 
 #[derive(Accounts)]
 pub struct InitializePlatform<'info> {
@@ -27,6 +27,15 @@ pub fn handler(
     config.platform_wallet = platform_wallet;
     config.oracle_authority = oracle_authority;
     config.authority = ctx.accounts.authority.key();
+    config.price_bounds = PriceBounds::DEFAULT;
+    // Curves freeze at the first buy, so holders never see a re-curve
+    config.price_lock_supply = 0;
+    config.fee_schedule = FeeSchedule::DEFAULT;
+    config.pauser = ctx.accounts.authority.key();
+    config.paused = false;
+    config.oracle_limits = OracleLimits::UNBOUNDED;
+    config.max_oracle_staleness_secs = i64::MAX;
+    config.genesis = GenesisConfig::DEFAULT;
     config.bump = ctx.bumps.platform_config;
     Ok(())
 }
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::PriceHistory;
+
+#[derive(Accounts)]
+pub struct InitializePriceHistory<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = PriceHistory::SPACE,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializePriceHistory>) -> Result<()> {
+    let mut price_history = ctx.accounts.price_history.load_init()?;
+    price_history.mint = ctx.accounts.mint.key();
+    price_history.bump = ctx.bumps.price_history;
+
+    msg!("Price history initialized successfully!");
+    Ok(())
+}
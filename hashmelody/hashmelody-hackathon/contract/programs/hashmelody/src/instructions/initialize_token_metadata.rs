@@ -1,8 +1,25 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
-use crate::state::{MintAuthority, TokenMetadata};
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::{
+    Mint,
+    spl_token_2022::{
+        self,
+        extension::{
+            BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+            metadata_pointer::MetadataPointer, transfer_fee::TransferFeeConfig,
+        },
+    },
+    spl_token_metadata_interface::state::TokenMetadata as NativeMetadata,
+    spl_pod::optional_keys::OptionalNonZeroPubkey,
+};
+use crate::state::{
+    MintAuthority, TokenMetadata, CreatorFees, PlatformConfig, FeeSchedule, MAX_NAME_LEN,
+    MAX_URI_LEN, TOKEN_DECIMALS,
+};
 use crate::error::TokenError;
+use crate::events::TokenCreated;
 
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(id: u64, name: String, music_uri: String)]
 pub struct InitializeTokenMetadata<'info> {
@@ -18,8 +35,16 @@ pub struct InitializeTokenMetadata<'info> {
     )]
     pub mint_authority: Account<'info, MintAuthority>,
 
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    // Whoever created the mint holds its keypair, so requiring it to sign
+    // stops anyone else from claiming the song as its creator
+    #[account(mut, signer @ TokenError::MintNotSigner)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 
     #[account(
         init_if_needed,
@@ -30,6 +55,15 @@ pub struct InitializeTokenMetadata<'info> {
     )]
     pub metadata: Account<'info, TokenMetadata>,
 
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = CreatorFees::SPACE,
+        seeds = [b"creator_fees", mint.key().as_ref()],
+        bump
+    )]
+    pub creator_fees: Account<'info, CreatorFees>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -40,19 +74,128 @@ pub fn handler(
     music_uri: String,
 ) -> Result<()> {
     // Validate lengths
-    require!(name.len() <= 16, TokenError::NameTooLong);
-    require!(music_uri.len() <= 44, TokenError::UriTooLong);
+    require!(name.len() <= MAX_NAME_LEN, TokenError::NameTooLong);
+    require!(music_uri.len() <= MAX_URI_LEN, TokenError::UriTooLong);
     
+    // The payer launching the song is its creator, and that can't change
+    // on a later call
+    let creator = ctx.accounts.payer.key();
+    let creator_fees = &mut ctx.accounts.creator_fees;
+    let is_new = creator_fees.creator == Pubkey::default();
+    if is_new {
+        creator_fees.mint = ctx.accounts.mint.key();
+        creator_fees.creator = creator;
+        creator_fees.bump = ctx.bumps.creator_fees;
+    }
+    require!(creator_fees.creator == creator, TokenError::UnauthorizedCreator);
+
+    // Only a fresh mint the program fully controls can become a song
+    if is_new {
+        validate_mint(&ctx.accounts.mint, &ctx.accounts.mint_authority.key(), &ctx.accounts.platform_config)?;
+    }
+
     // Initialize metadata
     let metadata = &mut ctx.accounts.metadata;
     metadata.mint = ctx.accounts.mint.key();
-    metadata.name = name;
-    metadata.music_uri = music_uri;   
+    metadata.creator = creator;
+    metadata.name = name.clone();
+    metadata.music_uri = music_uri.clone();
     metadata.id = id;
 
     // Store the bump in mint authority
     ctx.accounts.mint_authority.bump = ctx.bumps.mint_authority;
     
+    // Later calls only touch metadata, so the song is announced once
+    if is_new {
+        emit_cpi!(TokenCreated {
+            mint: ctx.accounts.mint.key(),
+            creator,
+            id,
+            name,
+            music_uri,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
     msg!("Token metadata initialized successfully!");
     Ok(())
-}
\ No newline at end of file
+}
+
+// Extensions a song mint may carry. The rest hand control to someone else:
+// a permanent delegate can move buyers' tokens, a transfer hook or frozen
+// default state can block sells, and a close authority can close the mint.
+const ALLOWED_MINT_EXTENSIONS: [ExtensionType; 3] = [
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::TransferFeeConfig,
+];
+
+// Anyone else holding the mint authority could mint outside the curve, and a
+// freeze authority outside the platform could lock buyers' tokens
+fn validate_mint(
+    mint: &InterfaceAccount<Mint>,
+    mint_authority: &Pubkey,
+    platform_config: &PlatformConfig,
+) -> Result<()> {
+    require!(
+        mint.mint_authority == COption::Some(*mint_authority),
+        TokenError::InvalidMintAuthority
+    );
+    let platform_freeze = match mint.freeze_authority {
+        COption::None => true,
+        COption::Some(freeze_authority) => freeze_authority == platform_config.authority,
+    };
+    require!(platform_freeze, TokenError::InvalidFreezeAuthority);
+    require!(mint.supply == 0, TokenError::MintSupplyNotZero);
+    require!(mint.decimals == TOKEN_DECIMALS, TokenError::InvalidMintDecimals);
+
+    // Legacy mints have no extensions and unpack the same way
+    let mint_info = mint.to_account_info();
+    let data = mint_info.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let extensions = state.get_extension_types()?;
+    for extension in &extensions {
+        require!(
+            ALLOWED_MINT_EXTENSIONS.contains(extension),
+            TokenError::UnsupportedMintExtension
+        );
+    }
+
+    // The allowed extensions stay safe only while the program, or nobody,
+    // can change them. Withheld fees must be withdrawable by the program so
+    // harvest_transfer_fees can pay them out.
+    if extensions.contains(&ExtensionType::MetadataPointer) {
+        let pointer = state.get_extension::<MetadataPointer>()?;
+        require!(
+            program_or_none(pointer.authority, mint_authority),
+            TokenError::InvalidExtensionAuthority
+        );
+    }
+    if extensions.contains(&ExtensionType::TokenMetadata) {
+        let metadata = state.get_variable_len_extension::<NativeMetadata>()?;
+        require!(
+            program_or_none(metadata.update_authority, mint_authority),
+            TokenError::InvalidExtensionAuthority
+        );
+    }
+    if extensions.contains(&ExtensionType::TransferFeeConfig) {
+        let fee_config = state.get_extension::<TransferFeeConfig>()?;
+        require!(
+            program_or_none(fee_config.transfer_fee_config_authority, mint_authority)
+                && Option::<Pubkey>::from(fee_config.withdraw_withheld_authority)
+                    == Some(*mint_authority),
+            TokenError::InvalidExtensionAuthority
+        );
+        for fee in [&fee_config.older_transfer_fee, &fee_config.newer_transfer_fee] {
+            require!(
+                u16::from(fee.transfer_fee_basis_points) as u64 <= FeeSchedule::MAX_FEE_BPS,
+                TokenError::FeeTooHigh
+            );
+        }
+    }
+    Ok(())
+}
+
+fn program_or_none(authority: OptionalNonZeroPubkey, mint_authority: &Pubkey) -> bool {
+    Option::<Pubkey>::from(authority).is_none_or(|authority| authority == *mint_authority)
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
-use crate::state::{ViewershipOracle, PriceParameters, TokenVault};
+use anchor_spl::token_interface::Mint;
+use crate::state::{
+    ViewershipOracle, CurveKind, TokenVault, PlatformConfig, LaunchSchedule, CreatorFees,
+};
+use crate::error::TokenError;
 
 // Second instruction: Initialize oracle and vault
 #[derive(Accounts)]
@@ -8,11 +11,28 @@ pub struct InitializeTokenOracle<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
+    // Only the song's creator picks its curve and launch schedule
     #[account(
-        init_if_needed,
+        seeds = [b"creator_fees", mint.key().as_ref()],
+        bump = creator_fees.bump,
+        constraint = creator_fees.creator == payer.key() @ TokenError::UnauthorizedCreator
+    )]
+    pub creator_fees: Account<'info, CreatorFees>,
+
+    // One-shot, so the curve, view count and TWAP of a live song can't be
+    // replaced here. Curve changes after launch go through
+    // update_price_params.
+    #[account(
+        init,
         payer = payer,
         space = ViewershipOracle::INIT_SPACE,
         seeds = [b"viewership_oracle", mint.key().as_ref()],
@@ -20,8 +40,10 @@ pub struct InitializeTokenOracle<'info> {
     )]
     pub oracle: Account<'info, ViewershipOracle>,
 
+    // One-shot, so a live song's pause flag, collected total and launch
+    // schedule can't be reset by calling this again
     #[account(
-        init_if_needed,
+        init,
         payer = payer,
         space = TokenVault::SPACE,
         seeds = [b"token_vault", mint.key().as_ref()],
@@ -32,27 +54,26 @@ pub struct InitializeTokenOracle<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitializeTokenOracle>) -> Result<()> {
-    // Initialize oracle
-    let oracle = &mut ctx.accounts.oracle;
-    oracle.mint = ctx.accounts.mint.key();
-    oracle.view_count = 0;
-    oracle.last_updated = Clock::get()?.unix_timestamp;
-    
-    // Set default price parameters
-    oracle.price_params = PriceParameters {
-        k: 1,
-        m: 100,
-    };
-    oracle.bump = ctx.bumps.oracle;
+pub fn handler(
+    ctx: Context<InitializeTokenOracle>,
+    curve: Option<CurveKind>,
+    launch: Option<LaunchSchedule>,
+) -> Result<()> {
+    // Songs without a chosen curve keep the original quadratic defaults
+    let curve = curve.unwrap_or_default();
+    curve.validate()?;
+    curve.check_bounds(&ctx.accounts.platform_config.price_bounds)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let launch = launch.unwrap_or(LaunchSchedule::immediate(now));
+    launch.validate()?;
+
+    // Initialize oracle. Nothing has been bought through the curve yet.
+    let mint_key = ctx.accounts.mint.key();
+    ctx.accounts.oracle.initialize(mint_key, curve, 0, now, ctx.bumps.oracle)?;
 
     // Initialize token vault
-    let vault = &mut ctx.accounts.token_vault;
-    vault.mint = ctx.accounts.mint.key();
-    vault.liquidity_threshold = 10_000_000_000; // 10 SOL
-    vault.raydium_pool = None;
-    vault.total_collected = 0;
-    vault.bump = ctx.bumps.token_vault;
+    ctx.accounts.token_vault.initialize(mint_key, launch, ctx.bumps.token_vault);
     
     msg!("Token oracle and vault initialized successfully!");
     Ok(())
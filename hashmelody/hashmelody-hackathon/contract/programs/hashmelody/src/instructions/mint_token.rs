@@ -1,15 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount, mint_to, MintTo},
+    token_interface::{Mint, TokenInterface, TokenAccount, mint_to, MintTo},
+    associated_token::AssociatedToken,
 };
-use crate::state::{MintAuthority};
+use crate::state::{MintAuthority, CreatorFees, PlatformConfig, GenesisAllocation};
 
-// Fourth instruction: Actually mint the tokens
+// Fourth instruction: Actually mint the tokens. The genesis allocation
+// account can only be created once, so this only ever succeeds once per
+// song.
 #[derive(Accounts)]
 pub struct MintToken<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     #[account(
         seeds = [b"mint_authority", mint.key().as_ref()],
         bump = mint_authority.bump,
@@ -17,64 +26,109 @@ pub struct MintToken<'info> {
     pub mint_authority: Account<'info, MintAuthority>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
-        mut,
-        constraint = token_account.mint == mint.key()
+        seeds = [b"creator_fees", mint.key().as_ref()],
+        bump = creator_fees.bump
+    )]
+    pub creator_fees: Account<'info, CreatorFees>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = GenesisAllocation::SPACE,
+        seeds = [b"genesis_allocation", mint.key().as_ref()],
+        bump
+    )]
+    pub genesis_allocation: Account<'info, GenesisAllocation>,
+
+    // Escrow for the creator's vesting share
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = genesis_allocation,
+        associated_token::token_program = token_program
     )]
-    pub token_account: Account<'info, TokenAccount>,
+    pub vesting_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = platform_token_account.mint == mint.key()
+        constraint = platform_token_account.mint == mint.key(),
+        constraint = platform_token_account.owner == platform_config.platform_wallet
     )]
-    pub platform_token_account: Account<'info, TokenAccount>,
+    pub platform_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<MintToken>) -> Result<()> {
-    // Fixed allocation amount
-    let allocation = 50_000_000; // 5% of 1 million tokens with 6 decimals
+    // Allocation sizes and the vesting schedule come from the platform
+    ctx.accounts.genesis_allocation.initialize(
+        ctx.accounts.mint.key(),
+        ctx.accounts.creator_fees.creator,
+        &ctx.accounts.platform_config.genesis,
+        Clock::get()?.unix_timestamp,
+        ctx.bumps.genesis_allocation,
+    );
+
+    mint_genesis_allocation(
+        &ctx.accounts.genesis_allocation,
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.mint_authority.to_account_info(),
+        ctx.accounts.mint_authority.bump,
+        ctx.accounts.vesting_token_account.to_account_info(),
+        ctx.accounts.platform_token_account.to_account_info(),
+    )?;
 
+    msg!("Tokens minted successfully!");
+    Ok(())
+}
+
+// Mints the creator's share into the vesting escrow and the platform's
+// share straight to the platform
+pub fn mint_genesis_allocation<'info>(
+    allocation: &GenesisAllocation,
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    mint_authority: AccountInfo<'info>,
+    mint_authority_bump: u8,
+    vesting_token_account: AccountInfo<'info>,
+    platform_token_account: AccountInfo<'info>,
+) -> Result<()> {
     // Create signer seeds for mint authority PDA
-    let mint_key = ctx.accounts.mint.key();
+    let mint_key = mint.key();
     let auth_seeds = &[
         b"mint_authority",
         mint_key.as_ref(),
-        &[ctx.accounts.mint_authority.bump],
+        &[mint_authority_bump],
     ];
     let signer_seeds = &[&auth_seeds[..]];
 
-    // Mint tokens to creator
-    mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.token_account.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        allocation,
-    )?;
-
-    // Mint tokens to platform
-    mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.platform_token_account.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        allocation,
-    )?;
-
-    msg!("Tokens minted successfully!");
+    let shares = [
+        (vesting_token_account, allocation.creator_amount),
+        (platform_token_account, allocation.platform_amount),
+    ];
+    for (to, amount) in shares {
+        if amount == 0 {
+            continue;
+        }
+        mint_to(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                MintTo {
+                    mint: mint.clone(),
+                    to,
+                    authority: mint_authority.clone(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+    }
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,3 +1,8 @@
+#![allow(ambiguous_glob_reexports)]
+
+pub mod create_song_token;
+pub use create_song_token::*;
+
 pub mod initialize_token_metadata;
 pub use initialize_token_metadata::*;
 
@@ -7,6 +12,9 @@ pub use initialize_token_oracle::*;
 pub mod setup_vault_account;
 pub use setup_vault_account::*;
 
+pub mod initialize_price_history;
+pub use initialize_price_history::*;
+
 pub mod setup_user_accounts;
 pub use setup_user_accounts::*;
 
@@ -19,14 +27,65 @@ pub use initialize_platform::*;
 pub mod update_platform;
 pub use update_platform::*;
 
+pub mod update_price_params;
+pub use update_price_params::*;
+
+pub mod set_platform_paused;
+pub use set_platform_paused::*;
+
+pub mod set_song_paused;
+pub use set_song_paused::*;
+
+pub mod set_stale_pricing;
+pub use set_stale_pricing::*;
+
 pub mod update_oracle;
 pub use update_oracle::*;
 
+pub mod update_oracle_attested;
+pub use update_oracle_attested::*;
+
+pub mod set_reporters;
+pub use set_reporters::*;
+
+pub mod submit_view_report;
+pub use submit_view_report::*;
+
 pub mod purchase_token;
 pub use purchase_token::*;
 
+// Shares the PurchaseToken accounts, so there is nothing to re-export
+pub mod purchase_token_exact_in;
+
+pub mod sell_token;
+pub use sell_token::*;
+
+pub mod claim_creator_fees;
+pub use claim_creator_fees::*;
+
+pub mod claim_vested;
+pub use claim_vested::*;
+
+pub mod harvest_transfer_fees;
+pub use harvest_transfer_fees::*;
+
+pub mod register_referrer;
+pub use register_referrer::*;
+
+pub mod claim_referral_fees;
+pub use claim_referral_fees::*;
+
 pub mod get_token_price;
 pub use get_token_price::*;
 
 pub mod get_vault_balance;
 pub use get_vault_balance::*;
+
+pub mod get_price_history;
+pub use get_price_history::*;
+
+pub mod get_twap;
+pub use get_twap::*;
+
+pub mod get_oracle_staleness;
+pub use get_oracle_staleness::*;
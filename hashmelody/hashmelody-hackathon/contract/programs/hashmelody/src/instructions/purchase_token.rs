@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount, mint_to, MintTo},
+    token_interface::{Mint, TokenInterface, TokenAccount, mint_to, MintTo},
     associated_token::AssociatedToken,
 };
-use crate::state::{MintAuthority, ViewershipOracle, PlatformConfig, TokenVault};
+use crate::state::{MintAuthority, ViewershipOracle, PlatformConfig, TokenVault, CreatorFees, Referral, FeeBreakdown, PriceHistory, LaunchAllocation};
 use crate::error::TokenError;
+use crate::events::{TradeEvent, VaultThresholdReached};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct PurchaseToken<'info> {
     #[account(mut)]
@@ -13,14 +15,16 @@ pub struct PurchaseToken<'info> {
 
     #[account(
         mut,
-        address = token_vault.sol_vault_wallet 
+        seeds = [b"sol_vault", token_vault.key().as_ref()],
+        bump = token_vault.sol_vault_bump
     )]
-    pub sol_vault_wallet: SystemAccount<'info>,
+    pub sol_vault: SystemAccount<'info>,
     
 
     #[account(
         seeds = [b"platform_config"],
-        bump = platform_config.bump
+        bump = platform_config.bump,
+        constraint = !platform_config.paused @ TokenError::PlatformPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
@@ -37,20 +41,22 @@ pub struct PurchaseToken<'info> {
     pub mint_authority: Account<'info, MintAuthority>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init_if_needed,
         payer = buyer,
         associated_token::mint = mint,
-        associated_token::authority = buyer
+        associated_token::authority = buyer,
+        associated_token::token_program = token_program
     )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
+    pub buyer_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
         seeds = [b"token_vault", mint.key().as_ref()],
-        bump = token_vault.bump
+        bump = token_vault.bump,
+        constraint = !token_vault.paused @ TokenError::SongPaused
     )]
     pub token_vault: Account<'info, TokenVault>,
 
@@ -58,16 +64,49 @@ pub struct PurchaseToken<'info> {
         mut,
         constraint = vault_token_account.key() == token_vault.vault_account
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
+        mut,
         seeds = [b"viewership_oracle", mint.key().as_ref()],
         bump = oracle.bump
     )]
     pub oracle: Account<'info, ViewershipOracle>,
 
+    #[account(
+        mut,
+        seeds = [b"creator_fees", mint.key().as_ref()],
+        bump = creator_fees.bump
+    )]
+    pub creator_fees: Account<'info, CreatorFees>,
+
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump = price_history.load()?.bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+
+    // Registered referrer who shared the song, if any
+    #[account(
+        mut,
+        seeds = [b"referral", referral.referrer.as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+
+    // Only needed while the song is in its launch window
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = LaunchAllocation::SPACE,
+        seeds = [b"launch_allocation", mint.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub launch_allocation: Option<Account<'info, LaunchAllocation>>,
+
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -75,106 +114,274 @@ pub struct PurchaseToken<'info> {
 pub fn handler(
     ctx: Context<PurchaseToken>,
     amount_tokens: u64,
+    max_total_cost_lamports: u64,
+    expires_at_unix: Option<i64>,
 ) -> Result<()> {
     // Validate amount is not zero
     require!(amount_tokens > 0, TokenError::InvalidAmount);
+
+    // Refuse to fill after the buyer's deadline
+    if let Some(expires_at) = expires_at_unix {
+        require!(
+            Clock::get()?.unix_timestamp <= expires_at,
+            TokenError::DeadlineExpired
+        );
+    }
     
-    // Get current supply and calculate price
-    let current_supply = ctx.accounts.mint.supply;
-    
-    // Check if new supply would overflow
-    let new_supply = current_supply
-        .checked_add(amount_tokens)
-        .ok_or(TokenError::SupplyOverflow)?;
-        
-    let price_per_token = ctx.accounts.oracle.calculate_price(current_supply)?;
-    
-    // Calculate total cost and validate
-    let total_cost = price_per_token
-        .checked_mul(amount_tokens)
-        .ok_or(TokenError::SupplyOverflow)?;
-    
-    // Calculate platform fee (2.5%) with overflow checks
-    let platform_fee = total_cost
-        .checked_mul(25)
-        .ok_or(TokenError::SupplyOverflow)?
-        .checked_div(1000)
-        .ok_or(TokenError::SupplyOverflow)?;
-    
-    let vault_amount = total_cost
-        .checked_sub(platform_fee)
+    // Get current supply and calculate cost. Only supply bought through the
+    // curve counts, since the genesis allocation put nothing in the vault.
+    let current_supply = ctx.accounts.token_vault.curve_supply;
+
+    // Charge the area under the curve over the bought range, so the total
+    // does not depend on how the order is split
+    let total_cost = ctx.accounts.oracle.calculate_cost(current_supply, amount_tokens)?;
+    require!(total_cost > 0, TokenError::InvalidAmount);
+
+    // Fees are charged on top of the curve cost, and the bound covers
+    // everything the buyer pays, even if an oracle update landed first
+    let fees = ctx.accounts.platform_config.fee_schedule.buy.breakdown(total_cost, ctx.accounts.referral.is_some())?;
+    let total_paid = total_cost
+        .checked_add(fees.total())
         .ok_or(TokenError::SupplyOverflow)?;
-    
-    // Check if the buyer has enough SOL
     require!(
-        ctx.accounts.buyer.lamports() >= total_cost,
-        TokenError::InsufficientFunds
+        total_paid <= max_total_cost_lamports,
+        TokenError::MaxCostExceeded
     );
 
-    msg!("Price per token: {} lamports", price_per_token);
-    msg!("Total cost: {} lamports", total_cost);
-    msg!("Platform fee: {} lamports", platform_fee);
-    msg!("Amount to vault: {} lamports", vault_amount);
-
-    // Transfer platform fee to platform wallet
-    anchor_lang::system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.buyer.to_account_info(),
-                to: ctx.accounts.platform_wallet.to_account_info(),
-            },
-        ),
-        platform_fee,
-    )?;
-
-    // Transfer remaining amount to token vault
-    anchor_lang::system_program::transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            anchor_lang::system_program::Transfer {
-                from: ctx.accounts.buyer.to_account_info(),
-                to: ctx.accounts.sol_vault_wallet.to_account_info(),
-            },
-        ),
-        vault_amount,
-    )?;
-
-    // Update vault total collected
-    let vault = &mut ctx.accounts.token_vault;
-    vault.total_collected = vault.total_collected
-        .checked_add(vault_amount)
-        .ok_or(TokenError::SupplyOverflow)?;
+    let trade = ctx.accounts.settle(amount_tokens, total_cost, fees)?;
+    emit_purchase_events(&ctx, trade)?;
+
+    msg!("Successfully purchased {} tokens", amount_tokens);
+    Ok(())
+}
 
-    // Check if we've hit the liquidity threshold
-    if vault.total_collected >= vault.liquidity_threshold {
-        if let Some(raydium_pool) = vault.raydium_pool {
-            msg!("Liquidity threshold reached! Consider calling provide_liquidity instruction");
+// Shared by both purchase paths. emit_cpi! needs the whole context, so
+// this cannot live on the accounts struct.
+pub fn emit_purchase_events(ctx: &Context<PurchaseToken>, trade: TradeEvent) -> Result<()> {
+    let vault = &ctx.accounts.token_vault;
+    let crossed_threshold = trade.total_collected_before < vault.liquidity_threshold
+        && trade.total_collected_after >= vault.liquidity_threshold;
+
+    if crossed_threshold {
+        emit_cpi!(VaultThresholdReached {
+            mint: trade.mint,
+            raydium_pool: vault.raydium_pool,
+            liquidity_threshold: vault.liquidity_threshold,
+            total_collected: trade.total_collected_after,
+            timestamp: trade.timestamp,
+        });
+    }
+
+    emit_cpi!(trade);
+    Ok(())
+}
+
+impl<'info> PurchaseToken<'info> {
+    // Collects `total_cost` plus `fees` from the buyer and mints
+    // `amount_tokens` to them. Returns the trade for the caller to emit.
+    pub fn settle(&mut self, amount_tokens: u64, total_cost: u64, fees: FeeBreakdown) -> Result<TradeEvent> {
+        let total_paid = total_cost
+            .checked_add(fees.total())
+            .ok_or(TokenError::SupplyOverflow)?;
+        let supply_before = self.token_vault.curve_supply;
+        let supply_after = supply_before
+            .checked_add(amount_tokens)
+            .ok_or(TokenError::SupplyOverflow)?;
+
+        // Check if the buyer has enough SOL
+        require!(
+            self.buyer.lamports() >= total_paid,
+            TokenError::InsufficientFunds
+        );
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        self.oracle.check_staleness(timestamp, self.platform_config.max_oracle_staleness_secs)?;
+        self.record_launch_purchase(timestamp, amount_tokens)?;
+
+        if let Some(referral) = &self.referral {
+            require!(referral.referrer != self.buyer.key(), TokenError::SelfReferral);
+        }
+
+        // The referrer's share is carved out of the platform fee
+        let platform_amount = fees.platform
+            .checked_sub(fees.referral)
+            .ok_or(TokenError::SupplyOverflow)?;
+
+        msg!("Curve cost: {} lamports", total_cost);
+        msg!("Platform fee: {} lamports", fees.platform);
+        msg!("Creator fee: {} lamports", fees.creator);
+        msg!("Referral fee: {} lamports", fees.referral);
+        msg!("Total paid: {} lamports", total_paid);
+
+        // Transfer platform fee to platform wallet
+        if platform_amount > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.buyer.to_account_info(),
+                        to: self.platform_wallet.to_account_info(),
+                    },
+                ),
+                platform_amount,
+            )?;
+        }
+
+        // Transfer referral fee to the referrer's account
+        if let Some(referral) = self.referral.as_mut() {
+            if fees.referral > 0 {
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: self.buyer.to_account_info(),
+                            to: referral.to_account_info(),
+                        },
+                    ),
+                    fees.referral,
+                )?;
+                referral.total_earned = referral.total_earned
+                    .checked_add(fees.referral)
+                    .ok_or(TokenError::SupplyOverflow)?;
+            }
+        }
+
+        // Transfer creator fee to the song's creator fee account
+        if fees.creator > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.buyer.to_account_info(),
+                        to: self.creator_fees.to_account_info(),
+                    },
+                ),
+                fees.creator,
+            )?;
+            self.creator_fees.total_accrued = self.creator_fees.total_accrued
+                .checked_add(fees.creator)
+                .ok_or(TokenError::SupplyOverflow)?;
         }
+
+        // Transfer the curve cost to the SOL vault
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: self.buyer.to_account_info(),
+                    to: self.sol_vault.to_account_info(),
+                },
+            ),
+            total_cost,
+        )?;
+
+        // Update vault total collected
+        let vault = &mut self.token_vault;
+        let total_collected_before = vault.total_collected;
+        vault.total_collected = vault.total_collected
+            .checked_add(total_cost)
+            .ok_or(TokenError::SupplyOverflow)?;
+        let total_collected_after = vault.total_collected;
+        vault.curve_supply = supply_after;
+
+        // Mint tokens to buyer
+        let mint_key = self.mint.key();
+        let mint_auth_seeds = &[
+            b"mint_authority".as_ref(),
+            mint_key.as_ref(),
+            &[self.mint_authority.bump],
+        ];
+        let signer_seeds = &[&mint_auth_seeds[..]];
+
+        mint_to(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                MintTo {
+                    mint: self.mint.to_account_info(),
+                    to: self.buyer_token_account.to_account_info(),
+                    authority: self.mint_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount_tokens,
+        )?;
+
+        let price_before = self.oracle.calculate_price(supply_before)?;
+        let price_after = self.oracle.calculate_price(supply_after)?;
+        self.oracle.accumulate_price(timestamp, price_after);
+        self.price_history.load_mut()?.record(
+            timestamp,
+            price_before,
+            price_after,
+            amount_tokens,
+            self.oracle.view_count,
+        );
+
+        Ok(TradeEvent {
+            mint: self.mint.key(),
+            trader: self.buyer.key(),
+            is_buy: true,
+            amount_tokens,
+            curve_amount: total_cost,
+            platform_fee: fees.platform,
+            creator_fee: fees.creator,
+            referral_fee: fees.referral,
+            referrer: self.referral.as_ref().map(|referral| referral.referrer),
+            view_count: self.oracle.view_count,
+            supply_before,
+            supply_after,
+            price_before,
+            price_after,
+            total_collected_before,
+            total_collected_after,
+            timestamp,
+        })
     }
 
-    // Mint tokens to buyer
-    let mint_key = ctx.accounts.mint.key();
-    let mint_auth_seeds = &[
-        b"mint_authority".as_ref(),
-        mint_key.as_ref(),
-        &[ctx.accounts.mint_authority.bump],
-    ];
-    let signer_seeds = &[&mint_auth_seeds[..]];
-
-    mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.buyer_token_account.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        amount_tokens,
-    )?;
+    // Most this buyer may still buy right now. Unlimited once the launch
+    // window has passed.
+    pub fn launch_allowance(&self, now: i64) -> Result<u64> {
+        let launch = &self.token_vault.launch;
+        require!(launch.has_started(now), TokenError::TradingNotStarted);
+        if !launch.in_window(now) {
+            return Ok(u64::MAX);
+        }
 
-    msg!("Successfully purchased {} tokens", amount_tokens);
-    Ok(())
-}
\ No newline at end of file
+        let allocation = self.launch_allocation
+            .as_ref()
+            .ok_or(TokenError::LaunchAllocationRequired)?;
+        let remaining = launch.max_per_wallet.saturating_sub(allocation.purchased);
+        Ok(remaining.min(launch.max_per_tx))
+    }
+
+    // Enforces the launch window limits and counts this buy against the
+    // buyer's allocation
+    fn record_launch_purchase(&mut self, now: i64, amount_tokens: u64) -> Result<()> {
+        let launch = self.token_vault.launch;
+        require!(launch.has_started(now), TokenError::TradingNotStarted);
+        if !launch.in_window(now) {
+            return Ok(());
+        }
+
+        require!(
+            amount_tokens <= launch.max_per_tx,
+            TokenError::LaunchTxLimitExceeded
+        );
+
+        let allocation = self.launch_allocation
+            .as_mut()
+            .ok_or(TokenError::LaunchAllocationRequired)?;
+        let purchased = allocation.purchased
+            .checked_add(amount_tokens)
+            .ok_or(TokenError::SupplyOverflow)?;
+        require!(
+            purchased <= launch.max_per_wallet,
+            TokenError::LaunchWalletCapExceeded
+        );
+
+        allocation.mint = self.mint.key();
+        allocation.buyer = self.buyer.key();
+        allocation.purchased = purchased;
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::instructions::{PurchaseToken, emit_purchase_events};
+use crate::error::TokenError;
+
+pub fn handler(
+    ctx: Context<PurchaseToken>,
+    lamports_in: u64,
+    min_tokens_out: u64,
+) -> Result<()> {
+    // Validate amount is not zero
+    require!(lamports_in > 0, TokenError::InvalidAmount);
+
+    // Set aside room for the buy fees, then solve the curve for the
+    // largest whole amount the rest covers
+    let buy_fees = ctx.accounts.platform_config.fee_schedule.buy;
+    let curve_budget = buy_fees.max_principal(lamports_in)?;
+    let current_supply = ctx.accounts.token_vault.curve_supply;
+    // During the launch window the buy is also capped at what the buyer
+    // has left of their allocation
+    let now = Clock::get()?.unix_timestamp;
+    let amount_tokens = ctx.accounts.oracle
+        .calculate_max_amount(current_supply, curve_budget)?
+        .min(ctx.accounts.launch_allowance(now)?);
+    require!(amount_tokens > 0, TokenError::InsufficientFunds);
+    require!(
+        amount_tokens >= min_tokens_out,
+        TokenError::SlippageExceeded
+    );
+
+    let total_cost = ctx.accounts.oracle.calculate_cost(current_supply, amount_tokens)?;
+    require!(total_cost > 0, TokenError::InvalidAmount);
+    let fees = buy_fees.breakdown(total_cost, ctx.accounts.referral.is_some())?;
+
+    // Only the exact cost and fees are taken from the buyer, so the dust
+    // that cannot buy another base unit never leaves their wallet
+    msg!("Refunded dust: {} lamports", lamports_in - total_cost - fees.total());
+
+    let trade = ctx.accounts.settle(amount_tokens, total_cost, fees)?;
+    emit_purchase_events(&ctx, trade)?;
+
+    msg!("Successfully purchased {} tokens", amount_tokens);
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::Referral;
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = Referral::SPACE,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referral = &mut ctx.accounts.referral;
+    referral.referrer = ctx.accounts.referrer.key();
+    referral.total_earned = 0;
+    referral.total_claimed = 0;
+    referral.bump = ctx.bumps.referral;
+
+    msg!("Referrer registered: {}", referral.referrer);
+    Ok(())
+}
@@ -0,0 +1,251 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_interface::{Mint, TokenInterface, TokenAccount, burn, Burn},
+    associated_token::AssociatedToken,
+};
+use crate::state::{ViewershipOracle, PlatformConfig, TokenVault, CreatorFees, PriceHistory};
+use crate::error::TokenError;
+use crate::events::TradeEvent;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SellToken<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_vault", token_vault.key().as_ref()],
+        bump = token_vault.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.paused @ TokenError::PlatformPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        address = platform_config.platform_wallet
+    )]
+    pub platform_wallet: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = seller,
+        associated_token::token_program = token_program
+    )]
+    pub seller_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"token_vault", mint.key().as_ref()],
+        bump = token_vault.bump,
+        constraint = !token_vault.paused @ TokenError::SongPaused
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    #[account(
+        mut,
+        seeds = [b"viewership_oracle", mint.key().as_ref()],
+        bump = oracle.bump
+    )]
+    pub oracle: Account<'info, ViewershipOracle>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_fees", mint.key().as_ref()],
+        bump = creator_fees.bump
+    )]
+    pub creator_fees: Account<'info, CreatorFees>,
+
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump = price_history.load()?.bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handler(
+    ctx: Context<SellToken>,
+    amount_tokens: u64,
+    min_lamports_out: u64,
+) -> Result<()> {
+    // Validate amount is not zero
+    require!(amount_tokens > 0, TokenError::InvalidAmount);
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.token_vault.launch.has_started(now),
+        TokenError::TradingNotStarted
+    );
+    require!(
+        ctx.accounts.seller_token_account.amount >= amount_tokens,
+        TokenError::InsufficientTokenBalance
+    );
+
+    // Pay out the area under the curve over the burned range, mirroring
+    // what buying the same range back would cost. Only supply bought
+    // through the curve is backed by the vault, so no more than that can
+    // be sold back into it.
+    let current_supply = ctx.accounts.token_vault.curve_supply;
+    let supply_after = current_supply
+        .checked_sub(amount_tokens)
+        .ok_or(TokenError::InsufficientVaultFunds)?;
+    let oracle = &ctx.accounts.oracle;
+    let is_stale = oracle.check_staleness(now, ctx.accounts.platform_config.max_oracle_staleness_secs)?;
+    let total_payout = if is_stale {
+        msg!("Oracle is stale, pricing without the view premium");
+        oracle.calculate_proceeds_without_views(current_supply, amount_tokens)?
+    } else {
+        oracle.calculate_proceeds(current_supply, amount_tokens)?
+    };
+
+    // Sell fees come out of the payout
+    let fees = ctx.accounts.platform_config.fee_schedule.sell.breakdown(total_payout, false)?;
+    let seller_amount = total_payout
+        .checked_sub(fees.total())
+        .ok_or(TokenError::SupplyOverflow)?;
+
+    require!(
+        seller_amount >= min_lamports_out,
+        TokenError::SlippageExceeded
+    );
+
+    // The vault can only pay out what purchases have put into it
+    require!(
+        total_payout <= ctx.accounts.token_vault.total_collected,
+        TokenError::InsufficientVaultFunds
+    );
+
+    msg!("Total payout: {} lamports", total_payout);
+    msg!("Platform fee: {} lamports", fees.platform);
+    msg!("Creator fee: {} lamports", fees.creator);
+    msg!("Referral fee: {} lamports", fees.referral);
+    msg!("Amount to seller: {} lamports", seller_amount);
+
+    // Burn the tokens being sold
+    burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.mint.to_account_info(),
+                from: ctx.accounts.seller_token_account.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        amount_tokens,
+    )?;
+
+    // The SOL vault is a PDA, so the program signs for it
+    let token_vault_key = ctx.accounts.token_vault.key();
+    let vault_seeds = &[
+        b"sol_vault".as_ref(),
+        token_vault_key.as_ref(),
+        &[ctx.accounts.token_vault.sol_vault_bump],
+    ];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    // Transfer platform fee from the vault to platform wallet
+    if fees.platform > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.sol_vault.to_account_info(),
+                    to: ctx.accounts.platform_wallet.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            fees.platform,
+        )?;
+    }
+
+    // Transfer creator fee from the vault to the creator fee account
+    if fees.creator > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.sol_vault.to_account_info(),
+                    to: ctx.accounts.creator_fees.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            fees.creator,
+        )?;
+        let creator_fees = &mut ctx.accounts.creator_fees;
+        creator_fees.total_accrued = creator_fees.total_accrued
+            .checked_add(fees.creator)
+            .ok_or(TokenError::SupplyOverflow)?;
+    }
+
+    // Transfer the remaining payout to the seller
+    anchor_lang::system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.sol_vault.to_account_info(),
+                to: ctx.accounts.seller.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        seller_amount,
+    )?;
+
+    // Update vault total collected
+    let vault = &mut ctx.accounts.token_vault;
+    let total_collected_before = vault.total_collected;
+    vault.total_collected = vault.total_collected
+        .checked_sub(total_payout)
+        .ok_or(TokenError::InsufficientVaultFunds)?;
+    let total_collected_after = vault.total_collected;
+    vault.curve_supply = supply_after;
+
+    let oracle = &mut ctx.accounts.oracle;
+    let price_before = oracle.calculate_price(current_supply)?;
+    let price_after = oracle.calculate_price(supply_after)?;
+    let timestamp = now;
+    oracle.accumulate_price(timestamp, price_after);
+    ctx.accounts.price_history.load_mut()?.record(
+        timestamp,
+        price_before,
+        price_after,
+        amount_tokens,
+        oracle.view_count,
+    );
+
+    emit_cpi!(TradeEvent {
+        mint: ctx.accounts.mint.key(),
+        trader: ctx.accounts.seller.key(),
+        is_buy: false,
+        amount_tokens,
+        curve_amount: total_payout,
+        platform_fee: fees.platform,
+        creator_fee: fees.creator,
+        referral_fee: fees.referral,
+        referrer: None,
+        view_count: oracle.view_count,
+        supply_before: current_supply,
+        supply_after,
+        price_before,
+        price_after,
+        total_collected_before,
+        total_collected_after,
+        timestamp,
+    });
+
+    msg!("Successfully sold {} tokens", amount_tokens);
+    Ok(())
+}
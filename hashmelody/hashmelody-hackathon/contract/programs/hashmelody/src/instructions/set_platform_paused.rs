@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::PlatformConfig;
+use crate::events::PauseUpdated;
+use crate::error::TokenError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPlatformPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = pauser @ TokenError::UnauthorizedPauser
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub pauser: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPlatformPaused>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.platform_config;
+    let previous_paused = config.paused;
+    config.paused = paused;
+
+    emit_cpi!(PauseUpdated {
+        mint: None,
+        pauser: ctx.accounts.pauser.key(),
+        previous_paused,
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
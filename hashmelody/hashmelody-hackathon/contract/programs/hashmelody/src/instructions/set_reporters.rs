@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{PlatformConfig, ReporterSet};
+use crate::events::ReporterSetUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReporters<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ReporterSet::SPACE,
+        seeds = [b"reporter_set"],
+        bump
+    )]
+    pub reporter_set: Account<'info, ReporterSet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetReporters>,
+    reporters: Vec<Pubkey>,
+    quorum: u8,
+    max_report_age_secs: i64,
+) -> Result<()> {
+    ReporterSet::validate(&reporters, quorum, max_report_age_secs)?;
+
+    // Reports from removed reporters stop counting straight away, since
+    // aggregation only reads reports from the current set
+    let reporter_set = &mut ctx.accounts.reporter_set;
+    let previous_reporters = std::mem::replace(&mut reporter_set.reporters, reporters);
+    let previous_quorum = reporter_set.quorum;
+    reporter_set.quorum = quorum;
+    reporter_set.max_report_age_secs = max_report_age_secs;
+    reporter_set.bump = ctx.bumps.reporter_set;
+
+    emit_cpi!(ReporterSetUpdated {
+        authority: ctx.accounts.authority.key(),
+        previous_reporters,
+        new_reporters: reporter_set.reporters.clone(),
+        previous_quorum,
+        new_quorum: quorum,
+        max_report_age_secs,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{PlatformConfig, TokenVault};
+use crate::events::PauseUpdated;
+use crate::error::TokenError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSongPaused<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = pauser @ TokenError::UnauthorizedPauser
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub pauser: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"token_vault", mint.key().as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+}
+
+pub fn handler(ctx: Context<SetSongPaused>, paused: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.token_vault;
+    let previous_paused = vault.paused;
+    vault.paused = paused;
+
+    emit_cpi!(PauseUpdated {
+        mint: Some(ctx.accounts.mint.key()),
+        pauser: ctx.accounts.pauser.key(),
+        previous_paused,
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
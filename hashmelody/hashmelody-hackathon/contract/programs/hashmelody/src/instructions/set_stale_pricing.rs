@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{ViewershipOracle, PlatformConfig, StalePricing};
+use crate::events::StalePricingUpdated;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetStalePricing<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub authority: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"viewership_oracle", mint.key().as_ref()],
+        bump = oracle.bump
+    )]
+    pub oracle: Account<'info, ViewershipOracle>,
+}
+
+pub fn handler(ctx: Context<SetStalePricing>, stale_pricing: StalePricing) -> Result<()> {
+    let oracle = &mut ctx.accounts.oracle;
+    let previous_stale_pricing = oracle.stale_pricing;
+    oracle.stale_pricing = stale_pricing;
+
+    emit_cpi!(StalePricingUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        previous_stale_pricing,
+        stale_pricing,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenInterface, TokenAccount},
     associated_token::AssociatedToken,
 };
 use crate::state::{TokenVault, PlatformConfig};
@@ -23,7 +23,7 @@ pub struct SetupTokenAccounts<'info> {
     pub platform_wallet: SystemAccount<'info>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         seeds = [b"token_vault", mint.key().as_ref()],
@@ -36,30 +36,33 @@ pub struct SetupTokenAccounts<'info> {
         init_if_needed,
         payer = payer,
         associated_token::mint = mint,
-        associated_token::authority = token_vault
+        associated_token::authority = token_vault,
+        associated_token::token_program = token_program
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = mint,
-        associated_token::authority = recipient
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
     )]
-    pub token_account: Account<'info, TokenAccount>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = mint,
-        associated_token::authority = platform_wallet
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
     )]
-    pub platform_token_account: Account<'info, TokenAccount>,
+    pub platform_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: This is the recipient's account
     pub recipient: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
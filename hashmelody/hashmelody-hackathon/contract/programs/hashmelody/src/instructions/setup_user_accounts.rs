@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenInterface, TokenAccount},
     associated_token::AssociatedToken,
 };
 use crate::state::PlatformConfig;
@@ -23,34 +23,36 @@ pub struct SetupUserAccounts<'info> {
     pub platform_wallet: SystemAccount<'info>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = mint,
-        associated_token::authority = recipient
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
     )]
-    pub token_account: Account<'info, TokenAccount>,
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         init_if_needed,
         payer = payer,
         associated_token::mint = mint,
-        associated_token::authority = platform_wallet
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
     )]
-    pub platform_token_account: Account<'info, TokenAccount>,
+    pub platform_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: This is the recipient's account
     pub recipient: UncheckedAccount<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<SetupUserAccounts>) -> Result<()> {
+pub fn handler(_ctx: Context<SetupUserAccounts>) -> Result<()> {
     msg!("User token accounts set up successfully!");
     Ok(())
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{Mint, Token, TokenAccount},
+    token_interface::{Mint, TokenInterface, TokenAccount},
     associated_token::AssociatedToken,
 };
 use crate::state::TokenVault;
@@ -12,7 +12,7 @@ pub struct SetupVaultAccount<'info> {
     pub payer: Signer<'info>,
 
     #[account(mut)]
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(
         seeds = [b"token_vault", mint.key().as_ref()],
@@ -25,25 +25,63 @@ pub struct SetupVaultAccount<'info> {
         init_if_needed,
         payer = payer,
         associated_token::mint = mint,
-        associated_token::authority = token_vault
+        associated_token::authority = token_vault,
+        associated_token::token_program = token_program
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
-    pub token_program: Program<'info, Token>,
+    // Holds the song's SOL reserve; only the program can sign for it
+    #[account(
+        mut,
+        seeds = [b"sol_vault", token_vault.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn handler(ctx: Context<SetupVaultAccount>) -> Result<()> {
-    // Store vault token account in vault
+    fund_sol_vault(
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.sol_vault.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.rent,
+    )?;
+
+    // Store vault token account and SOL vault in vault
     let vault = &mut ctx.accounts.token_vault;
-    
     vault.vault_account = ctx.accounts.vault_token_account.key();
+    vault.sol_vault = ctx.accounts.sol_vault.key();
+    vault.sol_vault_bump = ctx.bumps.sol_vault;
 
-    vault.sol_vault_wallet = ctx.accounts.payer.key(); 
-
-    
     msg!("Vault token account set up successfully!");
     Ok(())
-}
\ No newline at end of file
+}
+// Fund the SOL vault up to rent exemption so that deposits of any size
+// can land in it
+pub fn fund_sol_vault<'info>(
+    payer: AccountInfo<'info>,
+    sol_vault: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    rent: &Rent,
+) -> Result<()> {
+    let rent_reserve = rent.minimum_balance(0);
+    let shortfall = rent_reserve.saturating_sub(sol_vault.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program,
+                anchor_lang::system_program::Transfer {
+                    from: payer,
+                    to: sol_vault,
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    Ok(())
+}
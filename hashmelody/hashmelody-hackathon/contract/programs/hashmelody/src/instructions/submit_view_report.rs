@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{
+    ViewershipOracle, PlatformConfig, PriceHistory, TokenVault, ReporterSet, ViewReport, ViewReports,
+};
+use crate::instructions::apply_view_count;
+use crate::error::TokenError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SubmitViewReport<'info> {
+    #[account(
+        mut,
+        constraint = reporter_set.contains(&reporter.key()) @ TokenError::UnauthorizedOracleUpdate
+    )]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        seeds = [b"reporter_set"],
+        bump = reporter_set.bump
+    )]
+    pub reporter_set: Account<'info, ReporterSet>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.paused @ TokenError::PlatformPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = ViewReports::SPACE,
+        seeds = [b"view_reports", mint.key().as_ref()],
+        bump
+    )]
+    pub view_reports: Account<'info, ViewReports>,
+
+    #[account(
+        mut,
+        seeds = [b"viewership_oracle", mint.key().as_ref()],
+        bump = oracle.bump,
+    )]
+    pub oracle: Account<'info, ViewershipOracle>,
+
+    #[account(
+        seeds = [b"token_vault", mint.key().as_ref()],
+        bump = token_vault.bump,
+        constraint = !token_vault.paused @ TokenError::SongPaused
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump = price_history.load()?.bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitViewReport>, view_count: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let view_reports = &mut ctx.accounts.view_reports;
+    view_reports.mint = ctx.accounts.mint.key();
+    view_reports.bump = ctx.bumps.view_reports;
+    view_reports.submit(
+        &ctx.accounts.reporter_set,
+        ViewReport {
+            reporter: ctx.accounts.reporter.key(),
+            view_count,
+            timestamp: current_time,
+        },
+    );
+
+    let Some(median) = view_reports.median(&ctx.accounts.reporter_set, current_time) else {
+        msg!("Report recorded, waiting for quorum");
+        return Ok(());
+    };
+
+    // View counts only go up, and the aggregate is held to the same
+    // per-update limits as a single push. Reports that don't move the
+    // oracle are still kept for the next aggregation.
+    let oracle = &ctx.accounts.oracle;
+    if median <= oracle.view_count {
+        return Ok(());
+    }
+    if let Err(error) = ctx.accounts.platform_config.oracle_limits.check(
+        oracle.view_count,
+        median,
+        oracle.last_updated,
+        current_time,
+    ) {
+        msg!("Median {} not applied: {}", median, error);
+        return Ok(());
+    }
+
+    let event = apply_view_count(
+        &mut ctx.accounts.oracle,
+        &ctx.accounts.price_history,
+        ctx.accounts.token_vault.curve_supply,
+        median,
+        current_time,
+        ctx.accounts.reporter.key(),
+        false,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::Mint;
-use crate::state::{ViewershipOracle, PlatformConfig};
+use anchor_spl::token_interface::Mint;
+use crate::state::{ViewershipOracle, PlatformConfig, PriceHistory, TokenVault};
 use crate::error::TokenError;
+use crate::events::OracleUpdated;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateOracle<'info> {
     #[account(
@@ -12,16 +14,46 @@ pub struct UpdateOracle<'info> {
     )]
     pub oracle: Account<'info, ViewershipOracle>,
     
-    pub mint: Account<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
         seeds = [b"platform_config"],
         bump,
-        constraint = platform_config.oracle_authority == authority.key()
+        constraint = platform_config.oracle_authority == authority.key(),
+        constraint = !platform_config.paused @ TokenError::PlatformPaused
     )]
     pub platform_config: Account<'info, PlatformConfig>,
 
     pub authority: Signer<'info>,
+
+    // Second authority, needed only for pushes outside the oracle limits
+    #[account(
+        constraint = co_signer.key() == platform_config.oracle_limits.co_signer @ TokenError::UnauthorizedCoSigner
+    )]
+    pub co_signer: Option<Signer<'info>>,
+
+    /// CHECK: Only checked for existence. Once a reporter set is configured,
+    /// view counts come from its quorum and this path is an emergency
+    /// override that needs the co-signer.
+    #[account(
+        seeds = [b"reporter_set"],
+        bump
+    )]
+    pub reporter_set: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"token_vault", mint.key().as_ref()],
+        bump = token_vault.bump,
+        constraint = !token_vault.paused @ TokenError::SongPaused
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump = price_history.load()?.bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
 }
 
 pub fn handler(
@@ -38,21 +70,78 @@ pub fn handler(
 
     // Get current timestamp
     let current_time = Clock::get()?.unix_timestamp;
+
+    // A co-signed push may go past the configured limits
+    let co_signed = ctx.accounts.co_signer.is_some();
+    require!(
+        co_signed || ctx.accounts.reporter_set.data_is_empty(),
+        TokenError::ReporterQuorumRequired
+    );
+    if !co_signed {
+        ctx.accounts.platform_config.oracle_limits.check(
+            oracle.view_count,
+            new_view_count,
+            oracle.last_updated,
+            current_time,
+        )?;
+    }
+
+    let event = apply_view_count(
+        &mut ctx.accounts.oracle,
+        &ctx.accounts.price_history,
+        ctx.accounts.token_vault.curve_supply,
+        new_view_count,
+        current_time,
+        ctx.accounts.authority.key(),
+        co_signed,
+    )?;
+    emit_cpi!(event);
+    
+    Ok(())
+}
+
+// Moves the oracle to `new_view_count` and records the price move. Shared
+// by every path that updates view counts; the caller emits the event.
+pub fn apply_view_count(
+    oracle: &mut ViewershipOracle,
+    price_history: &AccountLoader<PriceHistory>,
+    current_supply: u64,
+    new_view_count: u64,
+    current_time: i64,
+    authority: Pubkey,
+    co_signed: bool,
+) -> Result<OracleUpdated> {
+    // Capture the previous values before they are overwritten
+    let previous_view_count = oracle.view_count;
+    let previous_updated_at = oracle.last_updated;
+    let previous_price = oracle.calculate_price(current_supply)?;
     
     // Update the oracle data
     oracle.view_count = new_view_count;
     oracle.last_updated = current_time;
     
     // Calculate the new price based on views and current supply
-    let current_supply = ctx.accounts.mint.supply;
     let new_price = oracle.calculate_price(current_supply)?;
+    oracle.accumulate_price(current_time, new_price);
     
-    // Emit events for monitoring
-    msg!("Updated view count for token: {}", ctx.accounts.mint.key());
-    msg!("Previous view count: {}", oracle.view_count);
-    msg!("New view count: {}", new_view_count);
-    msg!("New calculated price (scaled by 1e9): {}", new_price);
-    msg!("Last updated: {}", current_time);
-    
-    Ok(())
+    // A view update moves the price without any volume
+    price_history.load_mut()?.record(
+        current_time,
+        previous_price,
+        new_price,
+        0,
+        new_view_count,
+    );
+
+    Ok(OracleUpdated {
+        mint: oracle.mint,
+        authority,
+        previous_view_count,
+        new_view_count,
+        previous_price,
+        new_price,
+        previous_updated_at,
+        co_signed,
+        timestamp: current_time,
+    })
 }
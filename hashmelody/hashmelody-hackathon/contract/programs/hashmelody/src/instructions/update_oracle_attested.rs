@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::token_interface::Mint;
+use crate::state::{ViewershipOracle, PlatformConfig, PriceHistory, TokenVault, ViewCountAttestation};
+use crate::instructions::apply_view_count;
+use crate::error::TokenError;
+
+// Anyone can relay a view count the oracle authority signed off-chain. The
+// transaction must carry the matching ed25519 program instruction right
+// before this one.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateOracleAttested<'info> {
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        constraint = !platform_config.paused @ TokenError::PlatformPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"viewership_oracle", mint.key().as_ref()],
+        bump = oracle.bump,
+    )]
+    pub oracle: Account<'info, ViewershipOracle>,
+
+    #[account(
+        seeds = [b"token_vault", mint.key().as_ref()],
+        bump = token_vault.bump,
+        constraint = !token_vault.paused @ TokenError::SongPaused
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+
+    #[account(
+        mut,
+        seeds = [b"price_history", mint.key().as_ref()],
+        bump = price_history.load()?.bump
+    )]
+    pub price_history: AccountLoader<'info, PriceHistory>,
+
+    /// CHECK: Only checked for existence. Attestations carry a single
+    /// signature, so they stop once a reporter set is configured.
+    #[account(
+        seeds = [b"reporter_set"],
+        bump
+    )]
+    pub reporter_set: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked against the instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateOracleAttested>,
+    attestation: ViewCountAttestation,
+) -> Result<()> {
+    require!(
+        ctx.accounts.reporter_set.data_is_empty(),
+        TokenError::ReporterQuorumRequired
+    );
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let oracle_authority = ctx.accounts.platform_config.oracle_authority;
+
+    // Find the ed25519 instruction that precedes this one
+    let sysvar = ctx.accounts.instructions_sysvar.to_account_info();
+    let current_index = load_current_index_checked(&sysvar)?;
+    require!(current_index > 0, TokenError::MissingEd25519Instruction);
+    let ed25519_ix = load_instruction_at_checked(current_index as usize - 1, &sysvar)?;
+    attestation.verify(&ed25519_ix, &oracle_authority)?;
+
+    require_keys_eq!(
+        attestation.mint,
+        ctx.accounts.mint.key(),
+        TokenError::InvalidAttestation
+    );
+    attestation.check_fresh(current_time)?;
+
+    // Each attestation can be relayed once
+    let oracle = &mut ctx.accounts.oracle;
+    require!(
+        attestation.nonce > oracle.last_attestation_nonce,
+        TokenError::AttestationReplayed
+    );
+    oracle.last_attestation_nonce = attestation.nonce;
+
+    // Same rules as a push from the oracle authority without a co-signer
+    require!(
+        attestation.view_count >= oracle.view_count,
+        TokenError::InvalidViewCount
+    );
+    ctx.accounts.platform_config.oracle_limits.check(
+        oracle.view_count,
+        attestation.view_count,
+        oracle.last_updated,
+        current_time,
+    )?;
+
+    let event = apply_view_count(
+        &mut ctx.accounts.oracle,
+        &ctx.accounts.price_history,
+        ctx.accounts.token_vault.curve_supply,
+        attestation.view_count,
+        current_time,
+        oracle_authority,
+        false,
+    )?;
+    emit_cpi!(event);
+
+    Ok(())
+}
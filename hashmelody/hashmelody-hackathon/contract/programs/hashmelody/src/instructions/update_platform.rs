@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::PlatformConfig;
+use crate::state::{PlatformConfig, PriceBounds, FeeSchedule, OracleLimits, GenesisConfig};
+use crate::events::PlatformUpdated;
+use crate::error::TokenError;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdatePlatform<'info> {
     #[account(
@@ -14,18 +17,96 @@ pub struct UpdatePlatform<'info> {
     pub authority: Signer<'info>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handler(
     ctx: Context<UpdatePlatform>,
     new_platform_wallet: Pubkey,
     new_oracle_authority: Option<Pubkey>,
+    new_price_bounds: Option<PriceBounds>,
+    new_price_lock_supply: Option<u64>,
+    new_fee_schedule: Option<FeeSchedule>,
+    new_pauser: Option<Pubkey>,
+    new_oracle_limits: Option<OracleLimits>,
+    new_max_oracle_staleness_secs: Option<i64>,
+    new_genesis: Option<GenesisConfig>,
 ) -> Result<()> {
     let config = &mut ctx.accounts.platform_config;
+    let previous_platform_wallet = config.platform_wallet;
+    let previous_oracle_authority = config.oracle_authority;
+    let previous_price_bounds = config.price_bounds;
+    let previous_price_lock_supply = config.price_lock_supply;
+    let previous_fee_schedule = config.fee_schedule;
+    let previous_pauser = config.pauser;
+    let previous_oracle_limits = config.oracle_limits;
+    let previous_max_oracle_staleness_secs = config.max_oracle_staleness_secs;
+    let previous_genesis = config.genesis;
+
     config.platform_wallet = new_platform_wallet;
     
     // Update oracle authority if provided
     if let Some(new_authority) = new_oracle_authority {
         config.oracle_authority = new_authority;
     }
+
+    // Update curve parameter bounds if provided
+    if let Some(bounds) = new_price_bounds {
+        bounds.validate()?;
+        config.price_bounds = bounds;
+    }
+
+    if let Some(lock_supply) = new_price_lock_supply {
+        config.price_lock_supply = lock_supply;
+    }
+
+    // Update trading fees if provided
+    if let Some(fee_schedule) = new_fee_schedule {
+        fee_schedule.validate()?;
+        config.fee_schedule = fee_schedule;
+    }
+
+    if let Some(pauser) = new_pauser {
+        config.pauser = pauser;
+    }
+
+    if let Some(oracle_limits) = new_oracle_limits {
+        oracle_limits.validate()?;
+        config.oracle_limits = oracle_limits;
+    }
+
+    if let Some(max_staleness) = new_max_oracle_staleness_secs {
+        require!(max_staleness > 0, TokenError::InvalidOracleStaleness);
+        config.max_oracle_staleness_secs = max_staleness;
+    }
+
+    // Only songs launched after this take the new allocation terms
+    if let Some(genesis) = new_genesis {
+        genesis.validate()?;
+        config.genesis = genesis;
+    }
+
+    let config = &ctx.accounts.platform_config;
+    emit_cpi!(PlatformUpdated {
+        authority: ctx.accounts.authority.key(),
+        previous_platform_wallet,
+        new_platform_wallet: config.platform_wallet,
+        previous_oracle_authority,
+        new_oracle_authority: config.oracle_authority,
+        previous_price_bounds,
+        new_price_bounds: config.price_bounds,
+        previous_price_lock_supply,
+        new_price_lock_supply: config.price_lock_supply,
+        previous_fee_schedule,
+        new_fee_schedule: config.fee_schedule,
+        previous_pauser,
+        new_pauser: config.pauser,
+        previous_oracle_limits,
+        new_oracle_limits: config.oracle_limits,
+        previous_max_oracle_staleness_secs,
+        new_max_oracle_staleness_secs: config.max_oracle_staleness_secs,
+        previous_genesis,
+        new_genesis: config.genesis,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
     
     Ok(())
 }
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::{ViewershipOracle, PlatformConfig, CurveKind, TokenVault};
+use crate::events::PriceParamsUpdated;
+use crate::error::TokenError;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdatePriceParams<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub authority: Signer<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"viewership_oracle", mint.key().as_ref()],
+        bump = oracle.bump
+    )]
+    pub oracle: Account<'info, ViewershipOracle>,
+
+    #[account(
+        seeds = [b"token_vault", mint.key().as_ref()],
+        bump = token_vault.bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+}
+
+pub fn handler(
+    ctx: Context<UpdatePriceParams>,
+    new_curve: CurveKind,
+) -> Result<()> {
+    let config = &ctx.accounts.platform_config;
+    let supply = ctx.accounts.token_vault.curve_supply;
+
+    // Holders bought into the current curve, so it freezes once the song
+    // has real traction
+    require!(
+        supply <= config.price_lock_supply,
+        TokenError::PriceParamsLocked
+    );
+
+    new_curve.validate()?;
+    new_curve.check_bounds(&config.price_bounds)?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    let oracle = &mut ctx.accounts.oracle;
+    let previous_curve = oracle.curve;
+    oracle.curve = new_curve;
+    let new_price = oracle.calculate_price(supply)?;
+    oracle.accumulate_price(timestamp, new_price);
+
+    emit_cpi!(PriceParamsUpdated {
+        mint: ctx.accounts.mint.key(),
+        authority: ctx.accounts.authority.key(),
+        previous_curve,
+        new_curve,
+        supply,
+        timestamp,
+    });
+
+    Ok(())
+}
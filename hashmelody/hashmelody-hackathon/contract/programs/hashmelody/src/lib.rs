@@ -3,8 +3,15 @@ use anchor_lang::prelude::*;
 pub mod instructions;
 pub mod state;
 pub mod error;
+pub mod events;
+pub mod math;
+pub mod utils;
 
 use instructions::*;
+use state::{
+    CurveKind, PriceBounds, FeeSchedule, CandleData, LaunchSchedule, OracleLimits, ViewCountAttestation,
+    StalePricing, OracleStaleness, GenesisConfig, RoyaltyConfig,
+};
 
 declare_id!("8JUg9X2kSHvVgc2stoiAVwDoRtKZGEp2p42Z7Ficby6a");
 
@@ -20,12 +27,59 @@ pub mod hashmelody {
         instructions::initialize_platform::handler(ctx, platform_wallet, oracle_authority)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_platform(
         ctx: Context<UpdatePlatform>,
         new_platform_wallet: Pubkey,
         new_oracle_authority: Option<Pubkey>,
+        new_price_bounds: Option<PriceBounds>,
+        new_price_lock_supply: Option<u64>,
+        new_fee_schedule: Option<FeeSchedule>,
+        new_pauser: Option<Pubkey>,
+        new_oracle_limits: Option<OracleLimits>,
+        new_max_oracle_staleness_secs: Option<i64>,
+        new_genesis: Option<GenesisConfig>,
     ) -> Result<()> {
-        instructions::update_platform::handler(ctx, new_platform_wallet, new_oracle_authority)
+        instructions::update_platform::handler(
+            ctx,
+            new_platform_wallet,
+            new_oracle_authority,
+            new_price_bounds,
+            new_price_lock_supply,
+            new_fee_schedule,
+            new_pauser,
+            new_oracle_limits,
+            new_max_oracle_staleness_secs,
+            new_genesis,
+        )
+    }
+
+    // Launch a song in one transaction
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_song_token(
+        ctx: Context<CreateSongToken>,
+        id: u64,
+        name: String,
+        symbol: String,
+        music_uri: String,
+        artist: String,
+        genre: String,
+        curve: Option<CurveKind>,
+        launch: Option<LaunchSchedule>,
+        royalty: Option<RoyaltyConfig>,
+    ) -> Result<()> {
+        instructions::create_song_token::handler(
+            ctx,
+            id,
+            name,
+            symbol,
+            music_uri,
+            artist,
+            genre,
+            curve,
+            launch,
+            royalty,
+        )
     }
 
     // Step 1: Initialize token metadata and authority
@@ -41,8 +95,10 @@ pub mod hashmelody {
     // Step 2: Initialize oracle and vault
     pub fn initialize_token_oracle(
         ctx: Context<InitializeTokenOracle>,
+        curve: Option<CurveKind>,
+        launch: Option<LaunchSchedule>,
     ) -> Result<()> {
-        instructions::initialize_token_oracle::handler(ctx)
+        instructions::initialize_token_oracle::handler(ctx, curve, launch)
     }
 
     // Step 3: Set up vault token account
@@ -52,20 +108,47 @@ pub mod hashmelody {
         instructions::setup_vault_account::handler(ctx)
     }
 
-    // Step 4: Set up user token accounts
+    // Step 4: Initialize price history
+    pub fn initialize_price_history(ctx: Context<InitializePriceHistory>) -> Result<()> {
+        instructions::initialize_price_history::handler(ctx)
+    }
+
+    // Step 5: Set up user token accounts
     pub fn setup_user_accounts(
         ctx: Context<SetupUserAccounts>,
     ) -> Result<()> {
         instructions::setup_user_accounts::handler(ctx)
     }
 
-    // Step 5: Mint tokens
+    // Step 6: Mint tokens
     pub fn mint_token(
         ctx: Context<MintToken>,
     ) -> Result<()> {
         instructions::mint_token::handler(ctx)
     }
 
+    pub fn update_price_params(
+        ctx: Context<UpdatePriceParams>,
+        new_curve: CurveKind,
+    ) -> Result<()> {
+        instructions::update_price_params::handler(ctx, new_curve)
+    }
+
+    pub fn set_platform_paused(ctx: Context<SetPlatformPaused>, paused: bool) -> Result<()> {
+        instructions::set_platform_paused::handler(ctx, paused)
+    }
+
+    pub fn set_song_paused(ctx: Context<SetSongPaused>, paused: bool) -> Result<()> {
+        instructions::set_song_paused::handler(ctx, paused)
+    }
+
+    pub fn set_stale_pricing(
+        ctx: Context<SetStalePricing>,
+        stale_pricing: StalePricing,
+    ) -> Result<()> {
+        instructions::set_stale_pricing::handler(ctx, stale_pricing)
+    }
+
     pub fn update_oracle(
         ctx: Context<UpdateOracle>,
         new_view_count: u64,
@@ -73,11 +156,74 @@ pub mod hashmelody {
         instructions::update_oracle::handler(ctx, new_view_count)
     }
 
+    pub fn update_oracle_attested(
+        ctx: Context<UpdateOracleAttested>,
+        attestation: ViewCountAttestation,
+    ) -> Result<()> {
+        instructions::update_oracle_attested::handler(ctx, attestation)
+    }
+
+    pub fn set_reporters(
+        ctx: Context<SetReporters>,
+        reporters: Vec<Pubkey>,
+        quorum: u8,
+        max_report_age_secs: i64,
+    ) -> Result<()> {
+        instructions::set_reporters::handler(ctx, reporters, quorum, max_report_age_secs)
+    }
+
+    pub fn submit_view_report(
+        ctx: Context<SubmitViewReport>,
+        view_count: u64,
+    ) -> Result<()> {
+        instructions::submit_view_report::handler(ctx, view_count)
+    }
+
     pub fn purchase_token(
         ctx: Context<PurchaseToken>,
         amount_tokens: u64,
+        max_total_cost_lamports: u64,
+        expires_at_unix: Option<i64>,
     ) -> Result<()> {
-        instructions::purchase_token::handler(ctx, amount_tokens)
+        instructions::purchase_token::handler(ctx, amount_tokens, max_total_cost_lamports, expires_at_unix)
+    }
+
+    pub fn purchase_token_exact_in(
+        ctx: Context<PurchaseToken>,
+        lamports_in: u64,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::purchase_token_exact_in::handler(ctx, lamports_in, min_tokens_out)
+    }
+
+    pub fn sell_token(
+        ctx: Context<SellToken>,
+        amount_tokens: u64,
+        min_lamports_out: u64,
+    ) -> Result<()> {
+        instructions::sell_token::handler(ctx, amount_tokens, min_lamports_out)
+    }
+
+    pub fn claim_creator_fees(ctx: Context<ClaimCreatorFees>) -> Result<()> {
+        instructions::claim_creator_fees::handler(ctx)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        instructions::claim_vested::handler(ctx)
+    }
+
+    pub fn harvest_transfer_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, HarvestTransferFees<'info>>,
+    ) -> Result<()> {
+        instructions::harvest_transfer_fees::handler(ctx)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer::handler(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        instructions::claim_referral_fees::handler(ctx)
     }
 
     pub fn get_token_price(
@@ -91,4 +237,25 @@ pub mod hashmelody {
     ) -> Result<u64> {
         instructions::get_vault_balance::handler(ctx)
     }
+
+    pub fn get_price_history(
+        ctx: Context<GetPriceHistory>,
+        from_time: i64,
+        limit: u8,
+    ) -> Result<Vec<CandleData>> {
+        instructions::get_price_history::handler(ctx, from_time, limit)
+    }
+
+    pub fn get_twap(
+        ctx: Context<GetTwap>,
+        window_secs: i64,
+    ) -> Result<u64> {
+        instructions::get_twap::handler(ctx, window_secs)
+    }
+
+    pub fn get_oracle_staleness(
+        ctx: Context<GetOracleStaleness>,
+    ) -> Result<OracleStaleness> {
+        instructions::get_oracle_staleness::handler(ctx)
+    }
 }
\ No newline at end of file
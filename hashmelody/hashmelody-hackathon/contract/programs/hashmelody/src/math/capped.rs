@@ -0,0 +1,148 @@
+use super::curve::BondingCurve;
+use super::fixed_point::{to_u64, MathError, Rounding, U256};
+use super::linear::{LinearCurve, SLOPE_SCALE};
+use super::views::{view_area, view_price};
+
+/// Sigmoid-style curve: a linear ramp that flattens out at `max_price`.
+///
+/// price(x) = min(base_price + slope * x / SLOPE_SCALE, max_price) + m * sqrt(views) / VIEW_SCALE
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CappedCurve {
+    pub base_price: u64,
+    pub slope: u64,
+    pub max_price: u64,
+    pub m: u64,
+}
+
+impl BondingCurve for CappedCurve {
+    fn price(&self, supply: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        let ramp = self.ramp().price(supply, 0, rounding)?.min(self.max_price);
+        let price = U256::from(ramp)
+            .checked_add(view_price(self.m, views, rounding)?)
+            .ok_or(MathError::Overflow)?;
+        to_u64(price)
+    }
+
+    fn area(&self, from: u64, to: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        if to <= from {
+            return Ok(0);
+        }
+
+        let cap = U256::from(self.max_price);
+        let ramp = self.ramp();
+        let capped_area = match self.cap_crossover() {
+            // Flat from the start, at whichever of base and cap is lower
+            None => U256::from(self.base_price.min(self.max_price)) * U256::from(to - from),
+            // The whole range is still on the ramp
+            Some(crossover) if crossover >= to => U256::from(ramp.area(from, to, 0, rounding)?),
+            // The ramp hits the cap inside the range. Splitting on a whole
+            // base unit overcounts by at most the gap between the cap and the
+            // ramp at the split, so sells take that gap back off.
+            Some(crossover) if crossover >= from => {
+                let ramp_area = U256::from(ramp.area(from, crossover, 0, rounding)?);
+                let flat_area = cap * U256::from(to - crossover);
+                let gap = match rounding {
+                    Rounding::Up => U256::zero(),
+                    Rounding::Down => {
+                        cap.saturating_sub(U256::from(ramp.price(crossover, 0, Rounding::Down)?))
+                    }
+                };
+                ramp_area
+                    .checked_add(flat_area)
+                    .ok_or(MathError::Overflow)?
+                    .saturating_sub(gap)
+            }
+            _ => cap * U256::from(to - from),
+        };
+
+        let area = capped_area
+            .checked_add(view_area(self.m, views, to - from, rounding)?)
+            .ok_or(MathError::Overflow)?;
+        to_u64(area)
+    }
+}
+
+impl CappedCurve {
+    fn ramp(&self) -> LinearCurve {
+        LinearCurve {
+            base_price: self.base_price,
+            slope: self.slope,
+            m: 0,
+        }
+    }
+
+    // Last whole supply at which the ramp is still at or below the cap, or
+    // None when the curve never ramps
+    fn cap_crossover(&self) -> Option<u64> {
+        if self.slope == 0 || self.base_price >= self.max_price {
+            return None;
+        }
+        let crossover = (self.max_price - self.base_price) as u128 * SLOPE_SCALE / self.slope as u128;
+        Some(u64::try_from(crossover).unwrap_or(u64::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Ramps 3 lamports per base unit from 1_000 and caps at 1_100, so the
+    // ramp crosses the cap between units 33 (1_099) and 34 (1_102)
+    const CURVE: CappedCurve = CappedCurve {
+        base_price: 1_000,
+        slope: 3_000_000_000_000,
+        max_price: 1_100,
+        m: 100,
+    };
+
+    fn assert_brackets_unit_prices(curve: &CappedCurve, from: u64, to: u64, views: u64) {
+        let up = curve.area(from, to, views, Rounding::Up).unwrap() as u128;
+        let down = curve.area(from, to, views, Rounding::Down).unwrap() as u128;
+        let left: u128 = (from..to)
+            .map(|x| curve.price(x, views, Rounding::Down).unwrap() as u128)
+            .sum();
+        let right: u128 = (from..to)
+            .map(|x| curve.price(x + 1, views, Rounding::Up).unwrap() as u128)
+            .sum();
+        assert!(up >= left, "{from}..{to} views {views}");
+        assert!(down <= right, "{from}..{to} views {views}");
+        assert!(down <= up, "{from}..{to} views {views}");
+    }
+
+    #[test]
+    fn crossover_is_the_last_unit_on_the_ramp() {
+        assert_eq!(CURVE.cap_crossover(), Some(33));
+        assert_eq!(CURVE.price(33, 0, Rounding::Down).unwrap(), 1_099);
+        assert_eq!(CURVE.price(34, 0, Rounding::Down).unwrap(), 1_100);
+        assert_eq!(CURVE.price(1_000_000, 0, Rounding::Up).unwrap(), 1_100);
+    }
+
+    #[test]
+    fn ranges_bracket_unit_prices_on_every_branch() {
+        for views in [0, 10_000] {
+            // On the ramp, across the cap, exactly at it, and past it
+            for (from, to) in [(0, 30), (10, 60), (33, 34), (32, 35), (34, 300)] {
+                assert_brackets_unit_prices(&CURVE, from, to, views);
+            }
+        }
+    }
+
+    #[test]
+    fn range_past_the_cap_is_flat() {
+        for rounding in [Rounding::Up, Rounding::Down] {
+            assert_eq!(CURVE.area(100, 200, 0, rounding).unwrap(), 1_100 * 100);
+        }
+    }
+
+    #[test]
+    fn curves_that_never_ramp_have_no_crossover() {
+        let flat = CappedCurve { slope: 0, ..CURVE };
+        assert_eq!(flat.cap_crossover(), None);
+        assert_eq!(flat.area(0, 10, 0, Rounding::Up).unwrap(), 10_000);
+
+        let capped_at_base = CappedCurve { max_price: 900, ..CURVE };
+        assert_eq!(capped_at_base.cap_crossover(), None);
+        assert_eq!(capped_at_base.area(0, 10, 0, Rounding::Down).unwrap(), 9_000);
+        assert_eq!(capped_at_base.price(0, 0, Rounding::Down).unwrap(), 900);
+    }
+}
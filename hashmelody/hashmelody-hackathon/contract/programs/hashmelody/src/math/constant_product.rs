@@ -0,0 +1,59 @@
+use super::curve::BondingCurve;
+use super::fixed_point::{mul_div, to_u64, MathError, Rounding, U256};
+use super::views::{view_area, view_price};
+
+/// Constant-product curve over virtual reserves. The token reserve is
+/// `virtual_tokens - supply` and the SOL reserve is whatever keeps
+/// `sol * tokens = virtual_sol * virtual_tokens`.
+///
+/// price(x) = virtual_sol * virtual_tokens / (virtual_tokens - x)^2 + m * sqrt(views) / VIEW_SCALE
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstantProductCurve {
+    pub virtual_sol: u64,
+    pub virtual_tokens: u64,
+    pub m: u64,
+}
+
+impl BondingCurve for ConstantProductCurve {
+    fn price(&self, supply: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        let reserve = self.token_reserve(supply)?;
+        let marginal = mul_div(self.invariant(), U256::one(), reserve * reserve, rounding)?;
+        let price = marginal
+            .checked_add(view_price(self.m, views, rounding)?)
+            .ok_or(MathError::Overflow)?;
+        to_u64(price)
+    }
+
+    // Change in the SOL reserve between the two supplies, plus view term
+    fn area(&self, from: u64, to: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        if to <= from {
+            return Ok(0);
+        }
+
+        let upper = self.sol_reserve(to, rounding)?;
+        let lower = self.sol_reserve(from, rounding.reverse())?;
+        let area = upper
+            .saturating_sub(lower)
+            .checked_add(view_area(self.m, views, to - from, rounding)?)
+            .ok_or(MathError::Overflow)?;
+        to_u64(area)
+    }
+}
+
+impl ConstantProductCurve {
+    fn invariant(&self) -> U256 {
+        U256::from(self.virtual_sol) * U256::from(self.virtual_tokens)
+    }
+
+    // The curve is only defined while some virtual tokens remain
+    fn token_reserve(&self, supply: u64) -> Result<U256, MathError> {
+        match self.virtual_tokens.checked_sub(supply) {
+            Some(reserve) if reserve > 0 => Ok(U256::from(reserve)),
+            _ => Err(MathError::Overflow),
+        }
+    }
+
+    fn sol_reserve(&self, supply: u64, rounding: Rounding) -> Result<U256, MathError> {
+        mul_div(self.invariant(), U256::one(), self.token_reserve(supply)?, rounding)
+    }
+}
@@ -0,0 +1,204 @@
+use super::fixed_point::{MathError, Rounding};
+use super::search::max_amount_within;
+
+/// A price curve over token supply, in lamports per base unit.
+///
+/// Every curve also takes the song's view count, and must be non-decreasing
+/// in supply so that the area between two supplies is what a trade across
+/// that range costs (or pays).
+pub trait BondingCurve {
+    /// Spot price of the next base unit at `supply`
+    fn price(&self, supply: u64, views: u64, rounding: Rounding) -> Result<u64, MathError>;
+
+    /// Area under the curve between two supplies. This is what buying from
+    /// `from` up to `to` costs, or selling back down pays.
+    fn area(&self, from: u64, to: u64, views: u64, rounding: Rounding) -> Result<u64, MathError>;
+
+    /// Most base units that `budget` lamports can buy starting at `supply`
+    fn max_amount(&self, supply: u64, budget: u64, views: u64) -> Result<u64, MathError> {
+        // No unit past `supply` is cheaper than the spot price there, which
+        // bounds the search
+        let headroom = u64::MAX - supply;
+        let upper_bound = match self.price(supply, views, Rounding::Down)? {
+            0 => headroom,
+            price => (budget / price).min(headroom),
+        };
+        max_amount_within(budget, upper_bound, |amount| {
+            self.area(supply, supply + amount, views, Rounding::Up)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{CappedCurve, ConstantProductCurve, ExponentialCurve, LinearCurve, QuadraticCurve};
+
+    const VIEWS: [u64; 3] = [0, 7, 1_000_000];
+
+    // One curve of each family, with parameters that make every unit of a
+    // few-thousand-unit range change price
+    fn curves() -> Vec<(&'static str, Box<dyn BondingCurve>)> {
+        vec![
+            ("linear", Box::new(LinearCurve { base_price: 1_000, slope: 3_000_000_000_000, m: 100 })),
+            ("quadratic", Box::new(QuadraticCurve { k: 10_000_000_000_000_000_000, m: 100 })),
+            ("exponential", Box::new(ExponentialCurve { base_price: 1_000, doubling_supply: 700, m: 100 })),
+            (
+                "capped",
+                Box::new(CappedCurve {
+                    base_price: 1_000,
+                    slope: 7_000_000_000_000,
+                    max_price: 5_000,
+                    m: 100,
+                }),
+            ),
+            ("constant_product", Box::new(ConstantProductCurve { virtual_sol: 1_000_000_000, virtual_tokens: 10_000, m: 100 })),
+        ]
+    }
+
+    const RANGES: [(u64, u64); 4] = [(0, 1), (0, 600), (123, 1_000), (2_000, 2_600)];
+
+    #[test]
+    fn area_rounded_up_covers_left_unit_prices() {
+        for (name, curve) in curves() {
+            for views in VIEWS {
+                for (from, to) in RANGES {
+                    let area = curve.area(from, to, views, Rounding::Up).unwrap() as u128;
+                    let unit_prices: u128 = (from..to)
+                        .map(|x| curve.price(x, views, Rounding::Down).unwrap() as u128)
+                        .sum();
+                    assert!(area >= unit_prices, "{name} {from}..{to} views {views}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn area_rounded_down_stays_under_right_unit_prices() {
+        for (name, curve) in curves() {
+            for views in VIEWS {
+                for (from, to) in RANGES {
+                    let area = curve.area(from, to, views, Rounding::Down).unwrap() as u128;
+                    let unit_prices: u128 = (from..to)
+                        .map(|x| curve.price(x + 1, views, Rounding::Up).unwrap() as u128)
+                        .sum();
+                    assert!(area <= unit_prices, "{name} {from}..{to} views {views}");
+                }
+            }
+        }
+    }
+
+    // Splitting a trade never helps the trader: buys split into pieces cost
+    // at least as much, and sells split into pieces pay no more
+    #[test]
+    fn splitting_a_range_favors_the_protocol() {
+        let (from, to) = (100u64, 2_500u64);
+        for (name, curve) in curves() {
+            for views in VIEWS {
+                let whole_up = curve.area(from, to, views, Rounding::Up).unwrap();
+                let whole_down = curve.area(from, to, views, Rounding::Down).unwrap();
+                assert!(whole_down <= whole_up, "{name} views {views}");
+                for pieces in [2u64, 3, 17, 240] {
+                    let step = (to - from).div_ceil(pieces);
+                    let bounds: Vec<u64> = (from..to).step_by(step as usize).chain([to]).collect();
+                    let sum = |rounding| -> u64 {
+                        bounds
+                            .windows(2)
+                            .map(|pair| curve.area(pair[0], pair[1], views, rounding).unwrap())
+                            .sum()
+                    };
+                    assert!(sum(Rounding::Up) >= whole_up, "{name} buy in {pieces} views {views}");
+                    assert!(sum(Rounding::Down) <= whole_down, "{name} sell in {pieces} views {views}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn split_order_does_not_change_the_total() {
+        for (name, curve) in curves() {
+            for views in VIEWS {
+                for rounding in [Rounding::Up, Rounding::Down] {
+                    let area = |from, to| curve.area(from, to, views, rounding).unwrap();
+                    let small_first = area(500, 510) + area(510, 1_500);
+                    let large_first = area(500, 1_490) + area(1_490, 1_500);
+                    let whole = area(500, 1_500);
+                    // Each split can move the total by at most its rounding
+                    // and crossover slack
+                    let slack = curve.price(1_500, views, Rounding::Up).unwrap() + 2;
+                    assert!(small_first.abs_diff(whole) <= slack, "{name} {rounding:?}");
+                    assert!(large_first.abs_diff(whole) <= slack, "{name} {rounding:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn max_amount_is_exact_at_its_boundaries() {
+        for (name, curve) in curves() {
+            for views in VIEWS {
+                let supply = 250;
+                assert_eq!(curve.max_amount(supply, 0, views).unwrap(), 0, "{name}");
+                for amount in [1u64, 2, 999] {
+                    let cost = curve.area(supply, supply + amount, views, Rounding::Up).unwrap();
+                    assert_eq!(curve.max_amount(supply, cost, views).unwrap(), amount, "{name} {amount}");
+                    let short = curve.max_amount(supply, cost - 1, views).unwrap();
+                    assert!(short < amount, "{name} {amount}");
+                }
+            }
+        }
+    }
+
+    // With the whole u64 range as budget, one more unit must no longer be
+    // representable, or the search stopped early
+    #[test]
+    fn max_amount_spends_a_huge_budget_up_to_overflow() {
+        for (name, curve) in curves() {
+            let supply = 1_000;
+            let amount = curve.max_amount(supply, u64::MAX, 0).unwrap();
+            assert!(amount > 0, "{name}");
+            assert!(curve.area(supply, supply + amount, 0, Rounding::Up).is_ok(), "{name}");
+            assert!(curve.area(supply, supply + amount + 1, 0, Rounding::Up).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn max_amount_stops_short_of_the_constant_product_reserve() {
+        let curve = ConstantProductCurve { virtual_sol: 1_000_000_000, virtual_tokens: 10_000, m: 0 };
+        let amount = curve.max_amount(9_990, u64::MAX, 0).unwrap();
+        assert_eq!(amount, 9);
+    }
+
+    // A curve that opens at zero would hand out the first units for free
+    #[test]
+    fn validate_rejects_curves_that_open_at_zero() {
+        use crate::state::{
+            CappedParams, ConstantProductParams, CurveKind, ExponentialParams, LinearParams,
+            PriceParameters,
+        };
+
+        let free = [
+            CurveKind::Linear(LinearParams { base_price: 0, slope: 0, m: 0 }),
+            CurveKind::Linear(LinearParams { base_price: 0, slope: 1, m: 100 }),
+            CurveKind::Quadratic(PriceParameters { k: 0, m: 0 }),
+            CurveKind::Exponential(ExponentialParams { base_price: 0, doubling_supply: 700, m: 100 }),
+            CurveKind::Capped(CappedParams { base_price: 0, slope: 1, max_price: 0, m: 100 }),
+            CurveKind::ConstantProduct(ConstantProductParams { virtual_sol: 999, virtual_tokens: 1_000, m: 100 }),
+        ];
+        for curve in free {
+            assert!(curve.validate().is_err());
+        }
+
+        let priced = [
+            CurveKind::default(),
+            CurveKind::Linear(LinearParams { base_price: 1, slope: 0, m: 0 }),
+            CurveKind::Exponential(ExponentialParams { base_price: 1, doubling_supply: 700, m: 0 }),
+            CurveKind::Capped(CappedParams { base_price: 1, slope: 0, max_price: 1, m: 0 }),
+            CurveKind::ConstantProduct(ConstantProductParams { virtual_sol: 1_000, virtual_tokens: 1_000, m: 0 }),
+        ];
+        for curve in priced {
+            assert!(curve.validate().is_ok());
+            assert!(curve.curve().price(0, 0, Rounding::Down).unwrap() > 0);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use super::curve::BondingCurve;
+use super::fixed_point::{exp2, mul_div, to_u64, MathError, Rounding, LN2_Q64, Q64, U256};
+use super::views::{view_area, view_price};
+
+/// price(x) = base_price * 2^(x / doubling_supply) + m * sqrt(views) / VIEW_SCALE
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExponentialCurve {
+    pub base_price: u64,
+    pub doubling_supply: u64,
+    pub m: u64,
+}
+
+impl BondingCurve for ExponentialCurve {
+    fn price(&self, supply: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        let growth = mul_div(
+            U256::from(self.base_price),
+            exp2(supply, self.doubling_supply, rounding)?,
+            U256::from(Q64),
+            rounding,
+        )?;
+        let price = growth
+            .checked_add(view_price(self.m, views, rounding)?)
+            .ok_or(MathError::Overflow)?;
+        to_u64(price)
+    }
+
+    // base_price * doubling_supply * (2^(to/d) - 2^(from/d)) / ln2 + view term
+    fn area(&self, from: u64, to: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        if to <= from {
+            return Ok(0);
+        }
+
+        let upper = exp2(to, self.doubling_supply, rounding)?;
+        let lower = exp2(from, self.doubling_supply, rounding.reverse())?;
+        let ln2 = match rounding {
+            Rounding::Up => LN2_Q64,
+            Rounding::Down => LN2_Q64 + 1,
+        };
+        let growth = mul_div(
+            U256::from(self.base_price) * U256::from(self.doubling_supply),
+            upper.saturating_sub(lower),
+            U256::from(ln2),
+            rounding,
+        )?;
+        let area = growth
+            .checked_add(view_area(self.m, views, to - from, rounding)?)
+            .ok_or(MathError::Overflow)?;
+        to_u64(area)
+    }
+}
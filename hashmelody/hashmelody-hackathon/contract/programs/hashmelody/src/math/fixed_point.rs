@@ -0,0 +1,203 @@
+#![allow(clippy::assign_op_pattern, clippy::manual_div_ceil)]
+
+use uint::construct_uint;
+
+construct_uint! {
+    /// 256-bit unsigned integer for intermediate products. Cubes of a u64
+    /// supply need 192 bits, and the coefficient multiplied in on top needs
+    /// the rest.
+    pub struct U256(4);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    Overflow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Up,
+    Down,
+}
+
+impl Rounding {
+    pub fn reverse(self) -> Self {
+        match self {
+            Rounding::Up => Rounding::Down,
+            Rounding::Down => Rounding::Up,
+        }
+    }
+}
+
+/// 1.0 in Q64.64 fixed point
+pub const Q64: u128 = 1 << 64;
+
+/// ln(2) in Q64.64, rounded down
+pub const LN2_Q64: u128 = 12_786_308_645_202_655_659;
+
+/// `a * b / denominator`, rounded in the requested direction
+pub fn mul_div(a: U256, b: U256, denominator: U256, rounding: Rounding) -> Result<U256, MathError> {
+    if denominator.is_zero() {
+        return Err(MathError::Overflow);
+    }
+    let product = a.checked_mul(b).ok_or(MathError::Overflow)?;
+    let (quotient, remainder) = product.div_mod(denominator);
+    match rounding {
+        Rounding::Up if !remainder.is_zero() => {
+            quotient.checked_add(U256::one()).ok_or(MathError::Overflow)
+        }
+        _ => Ok(quotient),
+    }
+}
+
+/// Integer square root, rounded in the requested direction
+pub fn sqrt(value: u128, rounding: Rounding) -> u128 {
+    if value == 0 {
+        return 0;
+    }
+
+    // Newton's method from an initial guess that is always above the root
+    let mut x = 1u128 << ((128 - value.leading_zeros()).div_ceil(2));
+    loop {
+        let y = (x + value / x) >> 1;
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+
+    match rounding {
+        Rounding::Up if x * x < value => x + 1,
+        _ => x,
+    }
+}
+
+pub fn to_u64(value: U256) -> Result<u64, MathError> {
+    u64::try_from(value).map_err(|_| MathError::Overflow)
+}
+
+/// 2^(numerator / denominator) in Q64.64, rounded in the requested direction
+pub fn exp2(numerator: u64, denominator: u64, rounding: Rounding) -> Result<U256, MathError> {
+    if denominator == 0 {
+        return Err(MathError::Overflow);
+    }
+    // The fractional part stays below 2 in Q64.64, so shifting by the
+    // whole part has to leave room for 66 bits
+    let whole = numerator / denominator;
+    if whole > 189 {
+        return Err(MathError::Overflow);
+    }
+
+    // 2^fraction = e^(fraction * ln2)
+    let one = U256::from(Q64);
+    let fraction = mul_div(
+        U256::from(numerator % denominator),
+        one,
+        U256::from(denominator),
+        rounding,
+    )?;
+    let ln2 = match rounding {
+        Rounding::Up => LN2_Q64 + 1,
+        Rounding::Down => LN2_Q64,
+    };
+    let exponent = mul_div(fraction, U256::from(ln2), one, rounding)?;
+
+    // Taylor series for e^exponent. The exponent is below ln2, so each term
+    // is less than 0.7 of the one before and flooring every term keeps the
+    // sum a lower bound that is off by at most a few units per term.
+    let mut sum = one;
+    let mut term = one;
+    let mut terms = 0u64;
+    for n in 1..=40u64 {
+        term = term * exponent / (one * U256::from(n));
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        terms += 1;
+    }
+    if rounding == Rounding::Up {
+        sum += U256::from(4 * terms + 2);
+    }
+
+    Ok(sum << whole as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SQRT_INPUTS: [u128; 10] = [
+        1,
+        2,
+        3,
+        4,
+        99,
+        1_000_000,
+        (1 << 64) - 1,
+        1 << 64,
+        u128::MAX - 1,
+        u128::MAX,
+    ];
+
+    #[test]
+    fn sqrt_down_is_floor() {
+        for value in SQRT_INPUTS {
+            let root = sqrt(value, Rounding::Down);
+            assert!(root * root <= value, "sqrt({value}) too high");
+            let next = (root + 1).checked_mul(root + 1);
+            assert!(next.is_none_or(|square| square > value), "sqrt({value}) too low");
+        }
+        assert_eq!(sqrt(0, Rounding::Down), 0);
+    }
+
+    #[test]
+    fn sqrt_up_is_ceiling() {
+        for value in SQRT_INPUTS {
+            let root = sqrt(value, Rounding::Up);
+            assert!(
+                (root - 1) * (root - 1) < value,
+                "sqrt({value}) too high"
+            );
+            let square = U256::from(root) * U256::from(root);
+            assert!(square >= U256::from(value), "sqrt({value}) too low");
+        }
+        assert_eq!(sqrt(0, Rounding::Up), 0);
+    }
+
+    #[test]
+    fn exp2_whole_powers_bracket_exact_value() {
+        for whole in [0u64, 1, 5, 63, 189] {
+            let exact = U256::from(Q64) << whole as usize;
+            for denominator in [1u64, 7, 1_000_000] {
+                let numerator = whole * denominator;
+                let down = exp2(numerator, denominator, Rounding::Down).unwrap();
+                let up = exp2(numerator, denominator, Rounding::Up).unwrap();
+                assert!(down <= exact, "2^{whole} rounded down too high");
+                assert!(up >= exact, "2^{whole} rounded up too low");
+            }
+        }
+    }
+
+    // 2^(n/d) is bracketed exactly by checking (2^(n/d))^d against 2^n
+    #[test]
+    fn exp2_fractions_bracket_exact_value() {
+        for (numerator, denominator) in [(1u64, 2u64), (1, 3), (2, 3), (5, 2), (7, 3)] {
+            let down = exp2(numerator, denominator, Rounding::Down).unwrap();
+            let up = exp2(numerator, denominator, Rounding::Up).unwrap();
+            let power = |base: U256| {
+                (0..denominator).fold(U256::one(), |acc, _| acc * base)
+            };
+            let exact = (0..denominator).fold(U256::one(), |acc, _| acc * U256::from(Q64))
+                << numerator as usize;
+            assert!(power(down) <= exact, "2^({numerator}/{denominator}) rounded down too high");
+            assert!(power(up) >= exact, "2^({numerator}/{denominator}) rounded up too low");
+        }
+    }
+
+    #[test]
+    fn exp2_rejects_out_of_range() {
+        assert_eq!(exp2(190, 1, Rounding::Down), Err(MathError::Overflow));
+        assert_eq!(exp2(1, 0, Rounding::Up), Err(MathError::Overflow));
+    }
+}
@@ -0,0 +1,54 @@
+use super::curve::BondingCurve;
+use super::fixed_point::{mul_div, to_u64, MathError, Rounding, U256};
+use super::views::{view_area, view_price};
+
+/// Slope terms are `slope * supply / SLOPE_SCALE` lamports, so `slope` is the
+/// price added per 1e12 base units of supply (one million whole tokens)
+pub const SLOPE_SCALE: u128 = 1_000_000_000_000;
+
+/// price(x) = base_price + slope * x / SLOPE_SCALE + m * sqrt(views) / VIEW_SCALE
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearCurve {
+    pub base_price: u64,
+    pub slope: u64,
+    pub m: u64,
+}
+
+impl BondingCurve for LinearCurve {
+    fn price(&self, supply: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        let ramp = mul_div(
+            U256::from(self.slope),
+            U256::from(supply),
+            U256::from(SLOPE_SCALE),
+            rounding,
+        )?;
+        let view_term = view_price(self.m, views, rounding)?;
+        let price = U256::from(self.base_price)
+            .checked_add(ramp)
+            .and_then(|sum| sum.checked_add(view_term))
+            .ok_or(MathError::Overflow)?;
+        to_u64(price)
+    }
+
+    // base_price * (to - from) + slope * (to^2 - from^2) / 2 + view term
+    fn area(&self, from: u64, to: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        if to <= from {
+            return Ok(0);
+        }
+
+        let square = |x: u64| U256::from(x) * U256::from(x);
+        let amount = U256::from(to - from);
+        let ramp = mul_div(
+            U256::from(self.slope),
+            square(to) - square(from),
+            U256::from(2 * SLOPE_SCALE),
+            rounding,
+        )?;
+        let view_term = view_area(self.m, views, to - from, rounding)?;
+        let area = (U256::from(self.base_price) * amount)
+            .checked_add(ramp)
+            .and_then(|sum| sum.checked_add(view_term))
+            .ok_or(MathError::Overflow)?;
+        to_u64(area)
+    }
+}
@@ -0,0 +1,27 @@
+//! Deterministic integer pricing math.
+//!
+//! Nothing in this module depends on Anchor or the Solana runtime, and
+//! off-chain quoting services are expected to reproduce on-chain prices
+//! bit-for-bit. Every result is rounded explicitly, and
+//! callers pick the direction that favors the protocol: up for what a
+//! trader pays, down for what a trader receives.
+
+mod fixed_point;
+mod search;
+mod views;
+mod curve;
+mod linear;
+mod quadratic;
+mod exponential;
+mod capped;
+mod constant_product;
+
+pub use fixed_point::*;
+pub use search::*;
+pub use views::*;
+pub use curve::*;
+pub use linear::*;
+pub use quadratic::*;
+pub use exponential::*;
+pub use capped::*;
+pub use constant_product::*;
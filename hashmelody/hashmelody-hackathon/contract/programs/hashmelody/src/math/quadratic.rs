@@ -0,0 +1,192 @@
+use super::curve::BondingCurve;
+use super::fixed_point::{mul_div, to_u64, MathError, Rounding, U256};
+use super::views::{view_area, view_price, VIEW_SCALE, SQRT_SCALE, sqrt_views};
+
+/// Lowest price a base unit can trade at on the quadratic curve, in
+/// lamports (0.001 SOL)
+pub const MIN_PRICE: u64 = 1_000_000;
+
+/// Supply term is `k * supply^2 / QUADRATIC_SCALE` lamports. Folds the
+/// original 1e12 scaling of k, the 1e12 scaling of supply (squared) and the
+/// 1e5 lamport multiplier into one constant: 1e12 * 1e24 / 1e5 = 1e31
+pub const QUADRATIC_SCALE: u128 = 10_000_000_000_000_000_000_000_000_000_000;
+
+/// price(x) = max(k * x^2 / QUADRATIC_SCALE + m * sqrt(views) / VIEW_SCALE, MIN_PRICE)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuadraticCurve {
+    pub k: u64,
+    pub m: u64,
+}
+
+impl BondingCurve for QuadraticCurve {
+    fn price(&self, supply: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        let price = self.curve_price(supply, views, rounding)?;
+        Ok(to_u64(price)?.max(MIN_PRICE))
+    }
+
+    fn area(&self, from: u64, to: u64, views: u64, rounding: Rounding) -> Result<u64, MathError> {
+        if to <= from {
+            return Ok(0);
+        }
+
+        let floor = U256::from(MIN_PRICE);
+        let area = match self.floor_crossover(views)? {
+            // The whole range sits on the price floor
+            Some(crossover) if crossover >= to => floor * U256::from(to - from),
+            // The floor gives way to the curve inside the range. Splitting on
+            // a whole base unit undercounts by at most the gap between the
+            // floor and the curve at the split, so buys add that gap back.
+            Some(crossover) if crossover >= from => {
+                let floor_area = floor * U256::from(crossover - from);
+                let curve_area = self.curve_area(crossover, to, views, rounding)?;
+                let gap = match rounding {
+                    Rounding::Up => {
+                        floor.saturating_sub(self.curve_price(crossover, views, Rounding::Down)?)
+                    }
+                    Rounding::Down => U256::zero(),
+                };
+                floor_area
+                    .checked_add(curve_area)
+                    .and_then(|sum| sum.checked_add(gap))
+                    .ok_or(MathError::Overflow)?
+            }
+            _ => self.curve_area(from, to, views, rounding)?,
+        };
+
+        to_u64(area)
+    }
+}
+
+impl QuadraticCurve {
+    fn curve_price(&self, supply: u64, views: u64, rounding: Rounding) -> Result<U256, MathError> {
+        let supply = U256::from(supply);
+        let quadratic = mul_div(
+            U256::from(self.k),
+            supply * supply,
+            U256::from(QUADRATIC_SCALE),
+            rounding,
+        )?;
+        let view_term = view_price(self.m, views, rounding)?;
+        quadratic.checked_add(view_term).ok_or(MathError::Overflow)
+    }
+
+    // Integral of the unfloored curve: k * (to^3 - from^3) / 3 + view_term * (to - from)
+    fn curve_area(&self, from: u64, to: u64, views: u64, rounding: Rounding) -> Result<U256, MathError> {
+        let cube = |x: u64| {
+            let x = U256::from(x);
+            x * x * x
+        };
+        let quadratic = mul_div(
+            U256::from(self.k),
+            cube(to) - cube(from),
+            U256::from(3 * QUADRATIC_SCALE),
+            rounding,
+        )?;
+        let view_term = view_area(self.m, views, to - from, rounding)?;
+        quadratic.checked_add(view_term).ok_or(MathError::Overflow)
+    }
+
+    // Last whole supply at or below which the floor still applies, or None
+    // when the view term alone already clears the floor
+    fn floor_crossover(&self, views: u64) -> Result<Option<u64>, MathError> {
+        let scaled_floor = U256::from(MIN_PRICE) * U256::from(VIEW_SCALE * SQRT_SCALE);
+        let scaled_views = U256::from(self.m) * U256::from(sqrt_views(views, Rounding::Down));
+        if scaled_views >= scaled_floor {
+            return Ok(None);
+        }
+        if self.k == 0 {
+            return Ok(Some(u64::MAX));
+        }
+
+        // k * x^2 / QUADRATIC_SCALE = MIN_PRICE - view_term
+        let squared = mul_div(
+            scaled_floor - scaled_views,
+            U256::from(QUADRATIC_SCALE),
+            U256::from(self.k) * U256::from(VIEW_SCALE * SQRT_SCALE),
+            Rounding::Down,
+        )?;
+        Ok(Some(to_u64(squared.integer_sqrt()).unwrap_or(u64::MAX)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Crossover lands between whole base units, around 1.8e9
+    const CURVE: QuadraticCurve = QuadraticCurve { k: 3_000_000_000_000_000_000, m: 100 };
+
+    fn unit_price_bounds(curve: &QuadraticCurve, from: u64, to: u64, views: u64) -> (u128, u128) {
+        let left: u128 = (from..to)
+            .map(|x| curve.price(x, views, Rounding::Down).unwrap() as u128)
+            .sum();
+        let right: u128 = (from..to)
+            .map(|x| curve.price(x + 1, views, Rounding::Up).unwrap() as u128)
+            .sum();
+        (left, right)
+    }
+
+    #[test]
+    fn crossover_is_the_last_floored_unit() {
+        let crossover = CURVE.floor_crossover(0).unwrap().unwrap();
+        let scaled = |x: u64| U256::from(CURVE.k) * U256::from(x) * U256::from(x);
+        let floor = U256::from(MIN_PRICE) * U256::from(QUADRATIC_SCALE);
+        assert!(scaled(crossover) <= floor);
+        assert!(scaled(crossover + 1) > floor);
+        assert_eq!(CURVE.price(crossover, 0, Rounding::Up).unwrap(), MIN_PRICE);
+    }
+
+    #[test]
+    fn range_below_crossover_is_flat_at_the_floor() {
+        let crossover = CURVE.floor_crossover(0).unwrap().unwrap();
+        let (from, to) = (crossover - 1_000, crossover);
+        for rounding in [Rounding::Up, Rounding::Down] {
+            assert_eq!(CURVE.area(from, to, 0, rounding).unwrap(), MIN_PRICE * 1_000);
+        }
+    }
+
+    #[test]
+    fn range_across_crossover_brackets_unit_prices() {
+        for views in [0, 10_000] {
+            let crossover = CURVE.floor_crossover(views).unwrap().unwrap();
+            for (from, to) in [(crossover - 300, crossover + 300), (crossover, crossover + 1), (crossover - 1, crossover + 2)] {
+                let up = CURVE.area(from, to, views, Rounding::Up).unwrap() as u128;
+                let down = CURVE.area(from, to, views, Rounding::Down).unwrap() as u128;
+                let (left, right) = unit_price_bounds(&CURVE, from, to, views);
+                assert!(up >= left, "{from}..{to} views {views}");
+                assert!(down <= right, "{from}..{to} views {views}");
+                assert!(down <= up, "{from}..{to} views {views}");
+            }
+        }
+    }
+
+    #[test]
+    fn range_above_crossover_follows_the_curve() {
+        let crossover = CURVE.floor_crossover(0).unwrap().unwrap();
+        let (from, to) = (crossover + 10_000, crossover + 10_500);
+        let up = CURVE.area(from, to, 0, Rounding::Up).unwrap() as u128;
+        let down = CURVE.area(from, to, 0, Rounding::Down).unwrap() as u128;
+        let (left, right) = unit_price_bounds(&CURVE, from, to, 0);
+        assert!(left <= up && down <= right);
+        assert!(CURVE.price(from, 0, Rounding::Down).unwrap() > MIN_PRICE);
+    }
+
+    #[test]
+    fn view_premium_above_the_floor_removes_the_crossover() {
+        // 1e6 * sqrt(100) / VIEW_SCALE is exactly the floor
+        let curve = QuadraticCurve { k: 1, m: 1_000_000 };
+        assert_eq!(curve.floor_crossover(100).unwrap(), None);
+        assert!(curve.floor_crossover(99).unwrap().is_some());
+        assert_eq!(
+            curve.area(0, 10, 100, Rounding::Down).unwrap(),
+            curve.curve_area(0, 10, 100, Rounding::Down).unwrap().as_u64()
+        );
+    }
+
+    #[test]
+    fn zero_k_stays_on_the_floor() {
+        let curve = QuadraticCurve { k: 0, m: 100 };
+        assert_eq!(curve.floor_crossover(0).unwrap(), Some(u64::MAX));
+        assert_eq!(curve.area(5, 105, 0, Rounding::Up).unwrap(), MIN_PRICE * 100);
+    }
+}
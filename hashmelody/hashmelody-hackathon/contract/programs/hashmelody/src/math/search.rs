@@ -0,0 +1,22 @@
+use super::fixed_point::MathError;
+
+/// Largest amount in `0..=upper_bound` whose cost fits in `budget`.
+///
+/// `cost` must be non-decreasing in the amount, which holds for the area
+/// under any price curve. Amounts whose cost overflows count as
+/// unaffordable. Runs in log2(upper_bound) evaluations of `cost`.
+pub fn max_amount_within<F>(budget: u64, upper_bound: u64, mut cost: F) -> Result<u64, MathError>
+where
+    F: FnMut(u64) -> Result<u64, MathError>,
+{
+    let mut low = 0u64;
+    let mut high = upper_bound;
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        match cost(mid) {
+            Ok(total) if total <= budget => low = mid,
+            Ok(_) | Err(MathError::Overflow) => high = mid - 1,
+        }
+    }
+    Ok(low)
+}
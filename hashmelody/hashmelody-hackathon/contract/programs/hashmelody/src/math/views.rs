@@ -0,0 +1,34 @@
+use super::fixed_point::{mul_div, sqrt, MathError, Rounding, U256};
+
+/// View term is `m * sqrt(views) / VIEW_SCALE` lamports per base unit.
+/// Folds the original 1e6 scaling of m and the 1e5 lamport multiplier:
+/// 1e6 / 1e5 = 10
+pub const VIEW_SCALE: u128 = 10;
+
+/// sqrt(views) is carried as a fixed-point value with 9 decimals
+pub const SQRT_SCALE: u128 = 1_000_000_000;
+
+pub fn sqrt_views(views: u64, rounding: Rounding) -> u128 {
+    sqrt(views as u128 * SQRT_SCALE * SQRT_SCALE, rounding)
+}
+
+/// Premium the view count adds to the price of every base unit
+pub fn view_price(m: u64, views: u64, rounding: Rounding) -> Result<U256, MathError> {
+    mul_div(
+        U256::from(m),
+        U256::from(sqrt_views(views, rounding)),
+        U256::from(VIEW_SCALE * SQRT_SCALE),
+        rounding,
+    )
+}
+
+/// View premium over `amount` base units. The premium does not depend on
+/// supply, so this is a rectangle under the curve.
+pub fn view_area(m: u64, views: u64, amount: u64, rounding: Rounding) -> Result<U256, MathError> {
+    mul_div(
+        U256::from(m) * U256::from(sqrt_views(views, rounding)),
+        U256::from(amount),
+        U256::from(VIEW_SCALE * SQRT_SCALE),
+        rounding,
+    )
+}
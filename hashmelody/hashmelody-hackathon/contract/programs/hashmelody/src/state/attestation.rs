@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, instruction::Instruction};
+use crate::error::TokenError;
+
+pub const ATTESTATION_MAX_AGE_SECS: i64 = 300;
+pub const ATTESTATION_MAX_CLOCK_SKEW_SECS: i64 = 60;
+
+// Ed25519 program instruction layout: a count and padding byte, then one
+// 14-byte offsets record per signature
+const OFFSETS_START: usize = 2;
+const OFFSETS_SIZE: usize = 14;
+const PUBKEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+
+// Data lives in the ed25519 instruction itself rather than another one
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+// View count signed off-chain by the oracle authority. The signed message
+// is the borsh encoding of this struct.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ViewCountAttestation {
+    pub mint: Pubkey,
+    pub view_count: u64,
+    pub timestamp: i64,
+    pub nonce: u64,                  // Must increase with every attestation for a song
+}
+
+impl ViewCountAttestation {
+    pub fn message(&self) -> Result<Vec<u8>> {
+        Ok(self.try_to_vec()?)
+    }
+
+    // Checks that `ix` is an ed25519 program instruction verifying exactly
+    // one signature by `signer` over this attestation. The runtime already
+    // rejected the transaction if that signature was invalid.
+    pub fn verify(&self, ix: &Instruction, signer: &Pubkey) -> Result<()> {
+        require_keys_eq!(
+            ix.program_id,
+            ed25519_program::ID,
+            TokenError::MissingEd25519Instruction
+        );
+
+        let data = &ix.data;
+        require!(
+            data.len() >= OFFSETS_START + OFFSETS_SIZE && data[0] == 1,
+            TokenError::InvalidAttestation
+        );
+
+        let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+        let offsets = OFFSETS_START;
+        let signature_offset = read_u16(offsets) as usize;
+        let signature_ix = read_u16(offsets + 2);
+        let pubkey_offset = read_u16(offsets + 4) as usize;
+        let pubkey_ix = read_u16(offsets + 6);
+        let message_offset = read_u16(offsets + 8) as usize;
+        let message_size = read_u16(offsets + 10) as usize;
+        let message_ix = read_u16(offsets + 12);
+
+        // Offsets pointing into other instructions could verify a
+        // signature over something else entirely
+        require!(
+            signature_ix == CURRENT_INSTRUCTION
+                && pubkey_ix == CURRENT_INSTRUCTION
+                && message_ix == CURRENT_INSTRUCTION,
+            TokenError::InvalidAttestation
+        );
+
+        let slice = |offset: usize, size: usize| {
+            data.get(offset..offset + size).ok_or(TokenError::InvalidAttestation)
+        };
+        slice(signature_offset, SIGNATURE_SIZE)?;
+        let pubkey = slice(pubkey_offset, PUBKEY_SIZE)?;
+        let message = slice(message_offset, message_size)?;
+
+        require!(pubkey == signer.as_ref(), TokenError::InvalidAttestation);
+        require!(message == self.message()?.as_slice(), TokenError::InvalidAttestation);
+        Ok(())
+    }
+
+    pub fn check_fresh(&self, now: i64) -> Result<()> {
+        require!(
+            now.saturating_sub(self.timestamp) <= ATTESTATION_MAX_AGE_SECS
+                && self.timestamp.saturating_sub(now) <= ATTESTATION_MAX_CLOCK_SKEW_SECS,
+            TokenError::AttestationExpired
+        );
+        Ok(())
+    }
+}
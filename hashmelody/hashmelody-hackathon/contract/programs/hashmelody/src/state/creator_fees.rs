@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+// Per-song account that holds the creator's share of trading fees. The
+// lamports live on the account itself, above its rent-exempt minimum.
+#[account]
+pub struct CreatorFees {
+    pub mint: Pubkey,
+    pub creator: Pubkey,                 // Only key allowed to claim
+    pub total_accrued: u64,              // Lifetime creator fees
+    pub total_claimed: u64,              // Lifetime creator withdrawals
+    pub bump: u8,
+}
+
+impl CreatorFees {
+    pub const SPACE: usize = 8 +     // discriminator
+        32 +                         // mint
+        32 +                         // creator
+        8 +                          // total_accrued
+        8 +                          // total_claimed
+        1;                           // bump
+}
@@ -0,0 +1,201 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use crate::math::{
+    BondingCurve, CappedCurve, ConstantProductCurve, ExponentialCurve, LinearCurve, QuadraticCurve,
+};
+
+// Every parameter set carries `m`, the weight of the sqrt(views) premium
+// (see math::VIEW_SCALE). Prices are in lamports per base unit.
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct LinearParams {
+    pub base_price: u64,
+    pub slope: u64,                  // Price added per 1e12 base units (see math::SLOPE_SCALE)
+    pub m: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PriceParameters {
+    pub k: u64,                  // Quadratic growth factor (see math::QUADRATIC_SCALE)
+    pub m: u64,                  // View count scaling factor (see math::VIEW_SCALE)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ExponentialParams {
+    pub base_price: u64,
+    pub doubling_supply: u64,        // Supply over which the price doubles
+    pub m: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CappedParams {
+    pub base_price: u64,
+    pub slope: u64,                  // Price added per 1e12 base units (see math::SLOPE_SCALE)
+    pub max_price: u64,
+    pub m: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ConstantProductParams {
+    pub virtual_sol: u64,
+    pub virtual_tokens: u64,         // Supply can never reach this
+    pub m: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum CurveKind {
+    Linear(LinearParams),
+    Quadratic(PriceParameters),
+    Exponential(ExponentialParams),
+    Capped(CappedParams),
+    ConstantProduct(ConstantProductParams),
+}
+
+impl Default for CurveKind {
+    fn default() -> Self {
+        CurveKind::Quadratic(PriceParameters { k: 1, m: 100 })
+    }
+}
+
+impl CurveKind {
+    pub const SPACE: usize = 1 +     // variant
+        32;                          // largest parameter set (CappedParams)
+
+    pub fn curve(&self) -> Box<dyn BondingCurve> {
+        match *self {
+            CurveKind::Linear(p) => Box::new(LinearCurve {
+                base_price: p.base_price,
+                slope: p.slope,
+                m: p.m,
+            }),
+            CurveKind::Quadratic(p) => Box::new(QuadraticCurve { k: p.k, m: p.m }),
+            CurveKind::Exponential(p) => Box::new(ExponentialCurve {
+                base_price: p.base_price,
+                doubling_supply: p.doubling_supply,
+                m: p.m,
+            }),
+            CurveKind::Capped(p) => Box::new(CappedCurve {
+                base_price: p.base_price,
+                slope: p.slope,
+                max_price: p.max_price,
+                m: p.m,
+            }),
+            CurveKind::ConstantProduct(p) => Box::new(ConstantProductCurve {
+                virtual_sol: p.virtual_sol,
+                virtual_tokens: p.virtual_tokens,
+                m: p.m,
+            }),
+        }
+    }
+
+    // Every curve has to open at a positive price, or the first buys are free
+    pub fn validate(&self) -> Result<()> {
+        let valid = match *self {
+            CurveKind::Linear(p) => p.base_price > 0,
+            CurveKind::Quadratic(p) => p.k > 0,
+            CurveKind::Exponential(p) => p.base_price > 0 && p.doubling_supply > 0,
+            CurveKind::Capped(p) => p.base_price > 0 && p.max_price >= p.base_price,
+            // Opens at virtual_sol / virtual_tokens lamports per base unit
+            CurveKind::ConstantProduct(p) => {
+                p.virtual_tokens > 0 && p.virtual_sol >= p.virtual_tokens
+            }
+        };
+        require!(valid, TokenError::InvalidCurveParams);
+        Ok(())
+    }
+
+    // Every parameter the curve uses has to sit inside the platform bounds
+    pub fn check_bounds(&self, bounds: &PriceBounds) -> Result<()> {
+        let within = match *self {
+            CurveKind::Linear(p) => {
+                bounds.base_price.contains(p.base_price)
+                    && bounds.slope.contains(p.slope)
+                    && bounds.m.contains(p.m)
+            }
+            CurveKind::Quadratic(p) => bounds.k.contains(p.k) && bounds.m.contains(p.m),
+            CurveKind::Exponential(p) => {
+                bounds.base_price.contains(p.base_price)
+                    && bounds.doubling_supply.contains(p.doubling_supply)
+                    && bounds.m.contains(p.m)
+            }
+            CurveKind::Capped(p) => {
+                bounds.base_price.contains(p.base_price)
+                    && bounds.slope.contains(p.slope)
+                    && bounds.max_price.contains(p.max_price)
+                    && bounds.m.contains(p.m)
+            }
+            CurveKind::ConstantProduct(p) => {
+                bounds.virtual_sol.contains(p.virtual_sol)
+                    && bounds.virtual_tokens.contains(p.virtual_tokens)
+                    && bounds.m.contains(p.m)
+            }
+        };
+        require!(within, TokenError::PriceParamOutOfBounds);
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ParamBounds {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl ParamBounds {
+    // Admits only zero
+    pub const CLOSED: Self = Self { min: 0, max: 0 };
+
+    pub fn contains(&self, value: u64) -> bool {
+        self.min <= value && value <= self.max
+    }
+}
+
+// Platform-wide limits on every curve parameter, keyed by parameter name so
+// that families sharing a parameter share its bounds
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PriceBounds {
+    pub k: ParamBounds,
+    pub m: ParamBounds,
+    pub base_price: ParamBounds,
+    pub slope: ParamBounds,
+    pub max_price: ParamBounds,
+    pub doubling_supply: ParamBounds,
+    pub virtual_sol: ParamBounds,
+    pub virtual_tokens: ParamBounds,
+}
+
+impl PriceBounds {
+    pub const SPACE: usize = 8 * 16;   // eight (min, max) pairs
+
+    // Shipped bounds: quadratic curves within 100x of the default k = 1,
+    // m = 100. Every other family needs a nonzero base price or reserve, so
+    // closed bounds keep it off until the authority opens it through
+    // update_platform.
+    pub const DEFAULT: Self = Self {
+        k: ParamBounds { min: 1, max: 100 },
+        m: ParamBounds { min: 0, max: 10_000 },
+        base_price: ParamBounds::CLOSED,
+        slope: ParamBounds::CLOSED,
+        max_price: ParamBounds::CLOSED,
+        doubling_supply: ParamBounds::CLOSED,
+        virtual_sol: ParamBounds::CLOSED,
+        virtual_tokens: ParamBounds::CLOSED,
+    };
+
+    pub fn validate(&self) -> Result<()> {
+        let ordered = [
+            self.k,
+            self.m,
+            self.base_price,
+            self.slope,
+            self.max_price,
+            self.doubling_supply,
+            self.virtual_sol,
+            self.virtual_tokens,
+        ]
+        .iter()
+        .all(|bounds| bounds.min <= bounds.max);
+        require!(ordered, TokenError::InvalidPriceBounds);
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+// Fees charged on one side of a trade, in basis points of the curve amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct TradeFees {
+    pub platform_bps: u16,
+    pub creator_bps: u16,
+    pub referral_bps: u16,           // Share of the platform fee paid to a referrer
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct FeeSchedule {
+    pub buy: TradeFees,
+    pub sell: TradeFees,
+}
+
+// Token-2022 transfer fee withheld on every transfer of a song token,
+// harvested into creator and platform royalties
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct RoyaltyConfig {
+    pub basis_points: u16,
+    pub maximum_fee: u64,            // Cap per transfer, in base units
+}
+
+// Exact lamports each party received from one trade. `referral` is carved
+// out of `platform`, so `platform + creator` is everything the trader paid.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeBreakdown {
+    pub platform: u64,
+    pub creator: u64,
+    pub referral: u64,
+}
+
+impl TradeFees {
+    pub fn total_bps(&self) -> u64 {
+        self.platform_bps as u64 + self.creator_bps as u64
+    }
+
+    // Fees on `amount`, rounded up in the protocol's favor. Without a
+    // referrer the platform keeps the referral share.
+    pub fn breakdown(&self, amount: u64, referred: bool) -> Result<FeeBreakdown> {
+        let platform = bps_of(amount, self.platform_bps)?;
+        let creator = bps_of(amount, self.creator_bps)?;
+        let referral = if referred {
+            (platform as u128 * self.referral_bps as u128 / BPS_DENOMINATOR as u128) as u64
+        } else {
+            0
+        };
+        Ok(FeeBreakdown { platform, creator, referral })
+    }
+
+    // Largest curve amount that still fits in `total` once fees are added
+    pub fn max_principal(&self, total: u64) -> Result<u64> {
+        let mut principal = (total as u128 * BPS_DENOMINATOR as u128
+            / (BPS_DENOMINATOR + self.total_bps()) as u128) as u64;
+        // Rounding each fee up can push the estimate over by a few lamports.
+        // Compared in u128 since `total` may be close to u64::MAX.
+        while principal > 0
+            && principal as u128 + self.breakdown(principal, false)?.total() as u128 > total as u128
+        {
+            principal -= 1;
+        }
+        Ok(principal)
+    }
+
+    // Creator's part of `amount` in proportion to the creator and platform fees
+    pub fn creator_share(&self, amount: u64) -> u64 {
+        match self.total_bps() {
+            0 => 0,
+            total => (amount as u128 * self.creator_bps as u128 / total as u128) as u64,
+        }
+    }
+}
+
+impl FeeSchedule {
+    pub const SPACE: usize = 2 * 6;  // buy + sell, three u16 each
+
+    // Platform plus creator fees can never take more than 10% of a trade
+    pub const MAX_FEE_BPS: u64 = 1_000;
+
+    // The original flat 2.5% platform fee plus a 1% creator royalty
+    pub const DEFAULT: Self = Self {
+        buy: TradeFees { platform_bps: 250, creator_bps: 100, referral_bps: 0 },
+        sell: TradeFees { platform_bps: 250, creator_bps: 100, referral_bps: 0 },
+    };
+
+    pub fn validate(&self) -> Result<()> {
+        for side in [self.buy, self.sell] {
+            require!(
+                side.total_bps() <= Self::MAX_FEE_BPS,
+                TokenError::FeeTooHigh
+            );
+            require!(
+                side.referral_bps as u64 <= BPS_DENOMINATOR,
+                TokenError::FeeTooHigh
+            );
+        }
+        Ok(())
+    }
+}
+
+impl RoyaltyConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.basis_points as u64 <= FeeSchedule::MAX_FEE_BPS,
+            TokenError::FeeTooHigh
+        );
+        Ok(())
+    }
+}
+
+impl FeeBreakdown {
+    pub fn total(&self) -> u64 {
+        self.platform + self.creator
+    }
+}
+
+fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let fee = (amount as u128 * bps as u128).div_ceil(BPS_DENOMINATOR as u128);
+    u64::try_from(fee).map_err(|_| error!(TokenError::SupplyOverflow))
+}
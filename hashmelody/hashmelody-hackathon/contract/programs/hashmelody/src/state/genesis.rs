@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use crate::state::BPS_DENOMINATOR;
+
+// How each new song's genesis allocation is sized and how the creator's
+// share unlocks
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct GenesisConfig {
+    pub reference_supply: u64,       // Supply the shares below are taken of
+    pub creator_bps: u16,            // Vested to the creator
+    pub platform_bps: u16,           // Minted straight to the platform wallet
+    pub cliff_secs: i64,             // Nothing unlocks before the cliff
+    pub vesting_secs: i64,           // Linear unlock after the cliff
+}
+
+impl GenesisConfig {
+    pub const SPACE: usize = 8 +     // reference_supply
+        2 +                          // creator_bps
+        2 +                          // platform_bps
+        8 +                          // cliff_secs
+        8;                           // vesting_secs
+
+    // The original 50_000_000 base units to each side, unlocked at once
+    pub const DEFAULT: Self = Self {
+        reference_supply: 1_000_000_000,
+        creator_bps: 500,
+        platform_bps: 500,
+        cliff_secs: 0,
+        vesting_secs: 0,
+    };
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.creator_bps as u64 + self.platform_bps as u64 <= BPS_DENOMINATOR,
+            TokenError::InvalidGenesisConfig
+        );
+        require!(
+            self.cliff_secs >= 0 && self.vesting_secs >= 0,
+            TokenError::InvalidGenesisConfig
+        );
+        Ok(())
+    }
+
+    pub fn share(&self, bps: u16) -> u64 {
+        (self.reference_supply as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+}
+
+// Per-song record of the genesis allocation. Created exactly once when the
+// allocation is minted, and doubles as the escrow authority for the
+// creator's vesting tokens.
+#[account]
+pub struct GenesisAllocation {
+    pub mint: Pubkey,
+    pub creator: Pubkey,                 // Only key allowed to claim
+    pub creator_amount: u64,             // Held in escrow and vested
+    pub platform_amount: u64,            // Minted to the platform wallet
+    pub claimed: u64,                    // Vested tokens already withdrawn
+    pub start_time: i64,
+    pub cliff_secs: i64,
+    pub vesting_secs: i64,
+    pub bump: u8,
+}
+
+impl GenesisAllocation {
+    pub const SPACE: usize = 8 +     // discriminator
+        32 +                         // mint
+        32 +                         // creator
+        8 +                          // creator_amount
+        8 +                          // platform_amount
+        8 +                          // claimed
+        8 +                          // start_time
+        8 +                          // cliff_secs
+        8 +                          // vesting_secs
+        1;                           // bump
+
+    // Terms are copied from the platform config so that later config
+    // changes never alter a song's schedule
+    pub fn initialize(
+        &mut self,
+        mint: Pubkey,
+        creator: Pubkey,
+        config: &GenesisConfig,
+        now: i64,
+        bump: u8,
+    ) {
+        self.mint = mint;
+        self.creator = creator;
+        self.creator_amount = config.share(config.creator_bps);
+        self.platform_amount = config.share(config.platform_bps);
+        self.claimed = 0;
+        self.start_time = now;
+        self.cliff_secs = config.cliff_secs;
+        self.vesting_secs = config.vesting_secs;
+        self.bump = bump;
+    }
+
+    // Creator tokens unlocked by `now`: none before the cliff, then
+    // linearly over the vesting period
+    pub fn vested(&self, now: i64) -> u64 {
+        let unlock_start = self.start_time.saturating_add(self.cliff_secs);
+        if now < unlock_start {
+            return 0;
+        }
+        let elapsed = now - unlock_start;
+        if elapsed >= self.vesting_secs {
+            return self.creator_amount;
+        }
+        (self.creator_amount as u128 * elapsed as u128 / self.vesting_secs as u128) as u64
+    }
+
+    pub fn claimable(&self, now: i64) -> u64 {
+        self.vested(now).saturating_sub(self.claimed)
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+// When a song opens for trading, and the anti-sniper limits that apply to
+// buys during the first `window_secs` after that
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LaunchSchedule {
+    pub trading_starts_at: i64,
+    pub window_secs: i64,
+    pub max_per_tx: u64,             // Largest single buy during the window
+    pub max_per_wallet: u64,         // Most one wallet can buy during the window
+}
+
+impl LaunchSchedule {
+    pub const SPACE: usize = 8 + 8 + 8 + 8;
+
+    // Trading opens immediately with no launch limits
+    pub fn immediate(now: i64) -> Self {
+        Self {
+            trading_starts_at: now,
+            ..Self::default()
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let valid = self.window_secs == 0
+            || (self.window_secs > 0
+                && self.max_per_tx > 0
+                && self.max_per_wallet >= self.max_per_tx);
+        require!(valid, TokenError::InvalidLaunchSchedule);
+        Ok(())
+    }
+
+    pub fn has_started(&self, now: i64) -> bool {
+        now >= self.trading_starts_at
+    }
+
+    pub fn in_window(&self, now: i64) -> bool {
+        self.has_started(now) && now < self.trading_starts_at.saturating_add(self.window_secs)
+    }
+}
+
+// What one wallet has bought of one song during its launch window
+#[account]
+pub struct LaunchAllocation {
+    pub mint: Pubkey,
+    pub buyer: Pubkey,
+    pub purchased: u64,
+}
+
+impl LaunchAllocation {
+    pub const SPACE: usize = 8 +     // discriminator
+        32 +                         // mint
+        32 +                         // buyer
+        8;                           // purchased
+}
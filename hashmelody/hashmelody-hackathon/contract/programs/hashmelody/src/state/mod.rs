@@ -1,11 +1,31 @@
 mod mint_authority;
 mod token_metadata;
 mod platform_config;
+mod fee_schedule;
+mod oracle_limits;
+mod creator_fees;
+mod referral;
+mod curve;
 mod oracle;
+mod reporters;
+mod attestation;
 mod token_vault;
+mod price_history;
+mod launch;
+mod genesis;
 
 pub use mint_authority::*;
 pub use token_metadata::*;
 pub use platform_config::*;
+pub use fee_schedule::*;
+pub use oracle_limits::*;
+pub use creator_fees::*;
+pub use referral::*;
+pub use curve::*;
 pub use oracle::*;
+pub use reporters::*;
+pub use attestation::*;
 pub use token_vault::*;
+pub use price_history::*;
+pub use launch::*;
+pub use genesis::*;
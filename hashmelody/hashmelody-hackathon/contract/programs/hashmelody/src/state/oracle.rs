@@ -1,10 +1,37 @@
 use anchor_lang::prelude::*;
 use crate::error::TokenError;
+use crate::math::Rounding;
+use crate::state::CurveKind;
 
+pub const TWAP_OBSERVATIONS: usize = 12;
+pub const TWAP_PERIOD_SECS: i64 = 300;      // 12 x 5 minutes covers an hour
+
+// Snapshot of the price accumulator, written at most once per period
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PriceObservation {
+    pub timestamp: i64,
+    pub price_cumulative: u128,
+}
+
+// What trades on a song do once its oracle is older than the platform allows
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StalePricing {
+    #[default]
+    Reject,
+    // Sells are priced without the view premium. Buys keep the last
+    // reported count, which can only understate views, so pricing them
+    // without it would let buyers sell back at a profit after a refresh.
+    IgnoreViews,
+}
+
+// Snapshot returned by get_oracle_staleness
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
-pub struct PriceParameters {
-    pub k: u64,                  // Quadratic growth factor (scaled by 1e6)
-    pub m: u64,                  // View count scaling factor (scaled by 1e6)
+pub struct OracleStaleness {
+    pub last_updated: i64,
+    pub age_secs: i64,
+    pub max_staleness_secs: i64,
+    pub is_stale: bool,
+    pub stale_pricing: StalePricing,
 }
 
 #[account]
@@ -12,8 +39,15 @@ pub struct ViewershipOracle {
     pub mint: Pubkey,            
     pub view_count: u64,         
     pub last_updated: i64,       
-    pub price_params: PriceParameters,
+    pub curve: CurveKind,
     pub bump: u8,
+    pub last_price: u64,             // Spot price after the last state change
+    pub last_price_timestamp: i64,
+    pub price_cumulative: u128,      // Sum of last_price * seconds held, wrapping
+    pub observation_index: u8,       // Slot of the newest observation
+    pub observations: [PriceObservation; TWAP_OBSERVATIONS],
+    pub last_attestation_nonce: u64, // Highest attestation nonce applied
+    pub stale_pricing: StalePricing,
 }
 
 impl ViewershipOracle {
@@ -21,54 +55,138 @@ impl ViewershipOracle {
         32 +                              // mint
         8 +                              // view_count
         8 +                              // last_updated
-        16 +                             // price_params (k + m)
-        1;  
+        CurveKind::SPACE +               // curve
+        1 +                              // bump
+        8 +                              // last_price
+        8 +                              // last_price_timestamp
+        16 +                             // price_cumulative
+        1 +                              // observation_index
+        24 * TWAP_OBSERVATIONS +         // observations
+        8 +                              // last_attestation_nonce
+        1;                               // stale_pricing
         
-        pub fn calculate_price(&self, supply: u64) -> Result<u64> {
-            let k = self.price_params.k as f64 / 1_000_000_000_000.0;  // Scale k down by 1e12
-            let m = self.price_params.m as f64 / 1_000_000.0;
-            
-            // Convert and scale down supply for calculations
-            let supply_in_millions = (supply as f64) / 1_000_000_000_000.0;  // Scale down by 1e12
-            
-            // Calculate quadratic term with overflow check
-            let quadratic = k * supply_in_millions * supply_in_millions;
-            if quadratic.is_infinite() || quadratic.is_nan() {
-                msg!("Quadratic term overflow: k={}, supply={}", k, supply);
-                return Err(error!(TokenError::SupplyOverflow));
-            }
-            
-            // Calculate view term with overflow check
-            let view_sqrt = (self.view_count as f64).sqrt();
-            let views = m * view_sqrt;
-            if views.is_infinite() || views.is_nan() {
-                msg!("Views term overflow: m={}, view_count={}", m, self.view_count);
-                return Err(error!(TokenError::SupplyOverflow));
-            }
-            
-            // Calculate final price in lamports (scale back up but keep it reasonable)
-            let calculated_price = (quadratic + views) * 100_000.0; // Scale to lamports
-            
-            // Apply minimum price of 0.001 SOL (1,000,000 lamports)
-            let final_price = calculated_price.max(1_000_000.0);
-            
-            if final_price.is_infinite() || final_price.is_nan() || final_price >= u64::MAX as f64 {
-                msg!("Final price overflow: quadratic={}, views={}", quadratic, views);
-                return Err(error!(TokenError::SupplyOverflow));
-            }
-            
-            // Add debug logging
-            msg!("Price calculation:");
-            msg!("Supply: {}", supply);
-            msg!("View count: {}", self.view_count);
-            msg!("k: {}", k);
-            msg!("m: {}", m);
-            msg!("Quadratic term: {}", quadratic);
-            msg!("Views term: {}", views);
-            msg!("Calculated price (lamports): {}", calculated_price);
-            msg!("Final price with minimum (lamports): {}", final_price);
-            
-            Ok(final_price as u64)
+    // Spot price of the next token, rounded up as a quote to a buyer
+    pub fn calculate_price(&self, supply: u64) -> Result<u64> {
+        Ok(self.curve.curve().price(supply, self.view_count, Rounding::Up)?)
+    }
+
+    // Cost of buying `amount` tokens starting at `supply`, rounded up
+    pub fn calculate_cost(&self, supply: u64, amount: u64) -> Result<u64> {
+        let end = supply
+            .checked_add(amount)
+            .ok_or(TokenError::SupplyOverflow)?;
+        Ok(self.curve.curve().area(supply, end, self.view_count, Rounding::Up)?)
+    }
+
+    // Proceeds of selling `amount` tokens back from `supply`, rounded down
+    pub fn calculate_proceeds(&self, supply: u64, amount: u64) -> Result<u64> {
+        self.proceeds_at_views(supply, amount, self.view_count)
+    }
+
+    // Proceeds with the view premium left out, for a stale oracle
+    pub fn calculate_proceeds_without_views(&self, supply: u64, amount: u64) -> Result<u64> {
+        self.proceeds_at_views(supply, amount, 0)
+    }
+
+    fn proceeds_at_views(&self, supply: u64, amount: u64, views: u64) -> Result<u64> {
+        let start = supply
+            .checked_sub(amount)
+            .ok_or(TokenError::SupplyOverflow)?;
+        Ok(self.curve.curve().area(start, supply, views, Rounding::Down)?)
+    }
+
+    pub fn staleness(&self, now: i64, max_staleness_secs: i64) -> OracleStaleness {
+        let age_secs = now.saturating_sub(self.last_updated);
+        OracleStaleness {
+            last_updated: self.last_updated,
+            age_secs,
+            max_staleness_secs,
+            is_stale: age_secs > max_staleness_secs,
+            stale_pricing: self.stale_pricing,
+        }
+    }
+
+    // Fails on a stale oracle unless the song falls back to conservative
+    // pricing. Returns whether the oracle is stale.
+    pub fn check_staleness(&self, now: i64, max_staleness_secs: i64) -> Result<bool> {
+        let is_stale = self.staleness(now, max_staleness_secs).is_stale;
+        require!(
+            !is_stale || self.stale_pricing == StalePricing::IgnoreViews,
+            TokenError::OracleStale
+        );
+        Ok(is_stale)
+    }
+
+    // Most tokens that `budget` lamports can buy starting at `supply`
+    pub fn calculate_max_amount(&self, supply: u64, budget: u64) -> Result<u64> {
+        Ok(self.curve.curve().max_amount(supply, budget, self.view_count)?)
+    }
+
+    // Fresh oracle for a new song at zero views
+    pub fn initialize(
+        &mut self,
+        mint: Pubkey,
+        curve: CurveKind,
+        supply: u64,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        self.mint = mint;
+        self.view_count = 0;
+        self.last_updated = now;
+        self.curve = curve;
+        self.bump = bump;
+        let opening_price = self.calculate_price(supply)?;
+        self.init_twap(now, opening_price);
+        Ok(())
+    }
+
+    // Starts the accumulator at the song's opening price
+    pub fn init_twap(&mut self, timestamp: i64, price: u64) {
+        self.last_price = price;
+        self.last_price_timestamp = timestamp;
+        self.price_cumulative = 0;
+        self.observation_index = 0;
+        self.observations = [PriceObservation::default(); TWAP_OBSERVATIONS];
+        self.observations[0] = PriceObservation { timestamp, price_cumulative: 0 };
+    }
+
+    // Credits the time the previous price was in force, then moves on to
+    // `new_price`. Call after every change to supply, views or curve.
+    pub fn accumulate_price(&mut self, timestamp: i64, new_price: u64) {
+        self.price_cumulative = self.cumulative_at(timestamp);
+        self.last_price = new_price;
+        self.last_price_timestamp = timestamp;
+
+        let newest = self.observations[self.observation_index as usize];
+        if timestamp - newest.timestamp >= TWAP_PERIOD_SECS {
+            self.observation_index = ((self.observation_index as usize + 1) % TWAP_OBSERVATIONS) as u8;
+            self.observations[self.observation_index as usize] = PriceObservation {
+                timestamp,
+                price_cumulative: self.price_cumulative,
+            };
         }
-    
-}
\ No newline at end of file
+    }
+
+    // Average price from the newest observation at least `window_secs` old
+    // up to `timestamp`, so the averaged span is never shorter than asked
+    pub fn twap(&self, timestamp: i64, window_secs: i64) -> Result<u64> {
+        require!(window_secs > 0, TokenError::InvalidAmount);
+        let target = timestamp - window_secs;
+
+        let start = self.observations
+            .iter()
+            .filter(|observation| observation.timestamp > 0 && observation.timestamp <= target)
+            .max_by_key(|observation| observation.timestamp)
+            .ok_or(TokenError::TwapWindowTooLong)?;
+
+        let elapsed = (timestamp - start.timestamp) as u128;
+        let total = self.cumulative_at(timestamp).wrapping_sub(start.price_cumulative);
+        Ok((total / elapsed) as u64)
+    }
+
+    fn cumulative_at(&self, timestamp: i64) -> u128 {
+        let elapsed = timestamp.saturating_sub(self.last_price_timestamp).max(0) as u128;
+        self.price_cumulative.wrapping_add(self.last_price as u128 * elapsed)
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+use crate::state::BPS_DENOMINATOR;
+
+// Bounds on a single view-count push. Pushes outside them only go through
+// when `co_signer` also signs.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct OracleLimits {
+    pub min_update_interval_secs: i64,
+    pub max_growth_bps: u64,         // Largest rise relative to the previous count
+    pub max_delta: u64,              // Largest absolute rise
+    pub co_signer: Pubkey,           // Second authority that can approve larger pushes
+}
+
+impl OracleLimits {
+    pub const SPACE: usize = 8 + 8 + 8 + 32;
+
+    pub const UNBOUNDED: Self = Self {
+        min_update_interval_secs: 0,
+        max_growth_bps: u64::MAX,
+        max_delta: u64::MAX,
+        co_signer: Pubkey::new_from_array([0; 32]),
+    };
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_update_interval_secs >= 0,
+            TokenError::InvalidOracleLimits
+        );
+        Ok(())
+    }
+
+    pub fn check(
+        &self,
+        previous_view_count: u64,
+        new_view_count: u64,
+        last_updated: i64,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            now.saturating_sub(last_updated) >= self.min_update_interval_secs,
+            TokenError::OracleUpdateTooSoon
+        );
+
+        let delta = new_view_count - previous_view_count;
+        require!(delta <= self.max_delta, TokenError::OracleDeltaTooLarge);
+
+        // A ratio means nothing from zero, where only the delta applies
+        if previous_view_count > 0 {
+            let max_rise = previous_view_count as u128 * self.max_growth_bps as u128
+                / BPS_DENOMINATOR as u128;
+            require!(delta as u128 <= max_rise, TokenError::OracleGrowthTooLarge);
+        }
+        Ok(())
+    }
+}
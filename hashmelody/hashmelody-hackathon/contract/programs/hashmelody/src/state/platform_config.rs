@@ -1,10 +1,21 @@
 use anchor_lang::prelude::*;
+use crate::state::{FeeSchedule, GenesisConfig, OracleLimits, PriceBounds};
 
+// Layouts here and on per-song accounts changed with no migration; see
+// UPGRADING.md at the contract root
 #[account]
 pub struct PlatformConfig {
     pub platform_wallet: Pubkey,
     pub oracle_authority: Pubkey,    
     pub authority: Pubkey,
+    pub price_bounds: PriceBounds,
+    pub price_lock_supply: u64,      // Supply past which a song's curve is frozen
+    pub fee_schedule: FeeSchedule,
+    pub pauser: Pubkey,              // May pause and unpause trading
+    pub paused: bool,                // Halts trading and oracle updates on every song
+    pub oracle_limits: OracleLimits,
+    pub max_oracle_staleness_secs: i64, // Age past which trades treat the oracle as stale
+    pub genesis: GenesisConfig,      // Allocation minted when a song launches
     pub bump: u8,
 }
 
@@ -13,5 +24,13 @@ impl PlatformConfig {
         32 +                      // platform_wallet
         32 +                      // oracle_authority
         32 +                      // authority
+        PriceBounds::SPACE +      // price_bounds
+        8 +                       // price_lock_supply
+        FeeSchedule::SPACE +      // fee_schedule
+        32 +                      // pauser
+        1 +                       // paused
+        OracleLimits::SPACE +     // oracle_limits
+        8 +                       // max_oracle_staleness_secs
+        GenesisConfig::SPACE +    // genesis
         1;                        // bump
 }
\ No newline at end of file
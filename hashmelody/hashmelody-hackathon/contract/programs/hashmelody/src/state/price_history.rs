@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+pub const CANDLE_INTERVAL_SECS: i64 = 3_600;   // One candle per hour
+pub const MAX_CANDLES: usize = 128;             // Roughly five days of hourly candles
+
+// Return data is capped at 1024 bytes, which fits 18 candles
+pub const MAX_CANDLES_PER_READ: usize = 18;
+
+#[zero_copy]
+pub struct Candle {
+    pub start_time: i64,             // Start of the interval, aligned to CANDLE_INTERVAL_SECS
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    pub volume: u64,                 // Base units bought plus sold
+    pub view_count: u64,             // View count at the last update
+}
+
+// Ring buffer of the most recent candles for one song. Intervals with no
+// trades or oracle updates get no candle, so consumers should read gaps
+// from `start_time`.
+#[account(zero_copy)]
+pub struct PriceHistory {
+    pub mint: Pubkey,
+    pub head: u64,                   // Slot of the newest candle
+    pub len: u64,                    // Number of filled slots
+    pub candles: [Candle; MAX_CANDLES],
+    pub bump: u8,
+    pub _padding: [u8; 7],
+}
+
+// Borsh copy of a candle for returning from read-only instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct CandleData {
+    pub start_time: i64,
+    pub open: u64,
+    pub high: u64,
+    pub low: u64,
+    pub close: u64,
+    pub volume: u64,
+    pub view_count: u64,
+}
+
+impl PriceHistory {
+    pub const SPACE: usize = 8 + std::mem::size_of::<PriceHistory>();
+
+    // Folds a price move into the candle for `timestamp`, opening a new
+    // candle when the interval has rolled over
+    pub fn record(
+        &mut self,
+        timestamp: i64,
+        price_before: u64,
+        price_after: u64,
+        volume: u64,
+        view_count: u64,
+    ) {
+        let start_time = timestamp - timestamp.rem_euclid(CANDLE_INTERVAL_SECS);
+        let high = price_before.max(price_after);
+        let low = price_before.min(price_after);
+
+        if self.len > 0 && self.candles[self.head as usize].start_time == start_time {
+            let candle = &mut self.candles[self.head as usize];
+            candle.high = candle.high.max(high);
+            candle.low = candle.low.min(low);
+            candle.close = price_after;
+            candle.volume = candle.volume.saturating_add(volume);
+            candle.view_count = view_count;
+            return;
+        }
+
+        if self.len > 0 {
+            self.head = (self.head + 1) % MAX_CANDLES as u64;
+        }
+        self.len = (self.len + 1).min(MAX_CANDLES as u64);
+        self.candles[self.head as usize] = Candle {
+            start_time,
+            open: price_before,
+            high,
+            low,
+            close: price_after,
+            volume,
+            view_count,
+        };
+    }
+
+    // Oldest-first candles starting at or after `from_time`
+    pub fn window(&self, from_time: i64, limit: usize) -> Vec<CandleData> {
+        let oldest = (self.head + MAX_CANDLES as u64 + 1 - self.len) % MAX_CANDLES as u64;
+        (0..self.len)
+            .map(|i| self.candles[((oldest + i) % MAX_CANDLES as u64) as usize])
+            .filter(|candle| candle.start_time >= from_time)
+            .take(limit.min(MAX_CANDLES_PER_READ))
+            .map(|candle| CandleData {
+                start_time: candle.start_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                view_count: candle.view_count,
+            })
+            .collect()
+    }
+}
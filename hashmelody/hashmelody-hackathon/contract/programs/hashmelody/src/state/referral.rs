@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+// Per-referrer account that holds the referral share of platform fees
+// until the referrer claims it
+#[account]
+pub struct Referral {
+    pub referrer: Pubkey,
+    pub total_earned: u64,               // Lifetime referral earnings
+    pub total_claimed: u64,              // Lifetime referrer withdrawals
+    pub bump: u8,
+}
+
+impl Referral {
+    pub const SPACE: usize = 8 +     // discriminator
+        32 +                         // referrer
+        8 +                          // total_earned
+        8 +                          // total_claimed
+        1;                           // bump
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+pub const MAX_REPORTERS: usize = 8;
+
+// Keys allowed to report view counts, and how many fresh reports it takes
+// to move a song's oracle
+#[account]
+pub struct ReporterSet {
+    pub reporters: Vec<Pubkey>,
+    pub quorum: u8,
+    pub max_report_age_secs: i64,    // Older reports no longer count
+    pub bump: u8,
+}
+
+impl ReporterSet {
+    pub const SPACE: usize = 8 +     // discriminator
+        (4 + 32 * MAX_REPORTERS) +   // reporters
+        1 +                          // quorum
+        8 +                          // max_report_age_secs
+        1;                           // bump
+
+    pub fn validate(reporters: &[Pubkey], quorum: u8, max_report_age_secs: i64) -> Result<()> {
+        require!(
+            reporters.len() <= MAX_REPORTERS,
+            TokenError::InvalidReporterSet
+        );
+        let distinct = reporters
+            .iter()
+            .enumerate()
+            .all(|(i, reporter)| !reporters[..i].contains(reporter));
+        require!(distinct, TokenError::InvalidReporterSet);
+        require!(
+            quorum > 0 && quorum as usize <= reporters.len(),
+            TokenError::InvalidReporterSet
+        );
+        require!(max_report_age_secs > 0, TokenError::InvalidReporterSet);
+        Ok(())
+    }
+
+    pub fn contains(&self, reporter: &Pubkey) -> bool {
+        self.reporters.contains(reporter)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ViewReport {
+    pub reporter: Pubkey,
+    pub view_count: u64,
+    pub timestamp: i64,
+}
+
+// Latest report from each reporter for one song
+#[account]
+pub struct ViewReports {
+    pub mint: Pubkey,
+    pub reports: [ViewReport; MAX_REPORTERS],
+    pub bump: u8,
+}
+
+impl ViewReports {
+    pub const SPACE: usize = 8 +     // discriminator
+        32 +                         // mint
+        48 * MAX_REPORTERS +         // reports
+        1;                           // bump
+
+    // Replaces the reporter's previous report. New reporters take a slot
+    // that is empty or held by someone no longer in the set, else the
+    // oldest one.
+    pub fn submit(&mut self, set: &ReporterSet, report: ViewReport) {
+        let slot = self.reports
+            .iter()
+            .position(|existing| existing.reporter == report.reporter)
+            .or_else(|| {
+                self.reports
+                    .iter()
+                    .position(|existing| !set.contains(&existing.reporter))
+            })
+            .unwrap_or_else(|| {
+                (0..MAX_REPORTERS)
+                    .min_by_key(|&i| self.reports[i].timestamp)
+                    .unwrap_or(0)
+            });
+        self.reports[slot] = report;
+    }
+
+    // Median of fresh reports from current reporters, once a quorum of
+    // them has reported. With an even count the lower middle is used.
+    pub fn median(&self, set: &ReporterSet, now: i64) -> Option<u64> {
+        let mut counts: Vec<u64> = self.reports
+            .iter()
+            .filter(|report| set.contains(&report.reporter))
+            .filter(|report| now.saturating_sub(report.timestamp) <= set.max_report_age_secs)
+            .map(|report| report.view_count)
+            .collect();
+        if counts.is_empty() || counts.len() < set.quorum as usize {
+            return None;
+        }
+
+        counts.sort_unstable();
+        Some(counts[(counts.len() - 1) / 2])
+    }
+}
@@ -1,8 +1,15 @@
 use anchor_lang::prelude::*;
 
+pub const MAX_NAME_LEN: usize = 16;
+pub const MAX_URI_LEN: usize = 44;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_FIELD_LEN: usize = 32;        // Extra fields in Token-2022 metadata
+pub const TOKEN_DECIMALS: u8 = 6;
+
 #[account]
 pub struct TokenMetadata {
     pub mint: Pubkey,
+    pub creator: Pubkey,  // Receives the creator share of trading fees
     pub id: u64,
     pub name: String,  // We'll validate the length in the instruction handler
     pub music_uri: String,  // We'll validate the length in the instruction handler
@@ -11,7 +18,8 @@ pub struct TokenMetadata {
 impl TokenMetadata {
     pub const SPACE: usize = 8 +    // discriminator
         32 +                        // mint
+        32 +                        // creator
         8 +                         // id
-        (4 + 16) +                 // name (len + max chars)
-        (4 + 44);                  // music_uri (len + max chars)
+        (4 + MAX_NAME_LEN) +       // name (len + max chars)
+        (4 + MAX_URI_LEN);         // music_uri (len + max chars)
 }
\ No newline at end of file
@@ -1,23 +1,45 @@
 use anchor_lang::prelude::*;
+use crate::state::LaunchSchedule;
 
 #[account]
 pub struct TokenVault {
     pub mint: Pubkey,                    // Associated token mint
     pub vault_account: Pubkey,           // Token account holding collected SOL
-    pub sol_vault_wallet: Pubkey,        // For SOL (add this)
+    pub sol_vault: Pubkey,               // Program-owned PDA holding the SOL reserve
     pub raydium_pool: Option<Pubkey>,    // Associated Raydium pool if exists
     pub liquidity_threshold: u64,        // Threshold for auto-providing liquidity
     pub total_collected: u64,            // Total SOL collected
+    pub curve_supply: u64,               // Supply bought through the curve; trades are priced on this
+    pub launch: LaunchSchedule,          // Trading start and launch window limits
+    pub paused: bool,                    // Halts trading and oracle updates on this song
     pub bump: u8,
+    pub sol_vault_bump: u8,
 }
 
 impl TokenVault {
     pub const SPACE: usize = 8 +     // discriminator
         32 +                         // mint
         32 +                         // vault_account
-        32 +                         // sol_vault_wallet (add this)
+        32 +                         // sol_vault
         (1 + 32) +                  // Option<Pubkey> for raydium_pool
         8 +                         // liquidity_threshold
         8 +                         // total_collected
-        1;                          // bump
+        8 +                         // curve_supply
+        LaunchSchedule::SPACE +     // launch
+        1 +                         // paused
+        1 +                         // bump
+        1;                          // sol_vault_bump
+
+    // Empty vault for a new song. The token and SOL vault accounts are
+    // recorded once they exist.
+    pub fn initialize(&mut self, mint: Pubkey, launch: LaunchSchedule, bump: u8) {
+        self.mint = mint;
+        self.liquidity_threshold = 10_000_000_000; // 10 SOL
+        self.raydium_pool = None;
+        self.total_collected = 0;
+        self.curve_supply = 0;
+        self.launch = launch;
+        self.paused = false;
+        self.bump = bump;
+    }
 }
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+use crate::error::TokenError;
+
+// Moves everything a program-owned fee account holds above its rent-exempt
+// minimum to `to`, and returns the amount moved. The program owns the
+// account, so lamports move directly.
+pub fn withdraw_surplus_lamports(from: &AccountInfo, to: &AccountInfo, space: usize) -> Result<u64> {
+    let rent_reserve = Rent::get()?.minimum_balance(space);
+    let claimable = from.lamports().saturating_sub(rent_reserve);
+    require!(claimable > 0, TokenError::NoFeesToClaim);
+
+    **from.try_borrow_mut_lamports()? -= claimable;
+    **to.try_borrow_mut_lamports()? += claimable;
+    Ok(claimable)
+}